@@ -8,17 +8,39 @@ export const programId = new PublicKey(
   process.env.VITE_PROGRAM_ID || "YOUR_PROGRAM_ID"
 );
 
-export async function getCredentialAccount(hash: string) {
-  // Implementation would depend on your specific Solana program structure
-  // This is just a placeholder for the actual implementation
-  return await connection.getProgramAccounts(programId, {
-    filters: [
-      {
-        memcmp: {
-          offset: 8, // Skip discriminator
-          bytes: hash,
-        },
-      },
-    ],
-  });
+const ISSUER_SEED = new TextEncoder().encode("issuer");
+const CREDENTIAL_SEED = new TextEncoder().encode("credential");
+
+// Issuer registry accounts live at PDA [b"issuer", authority], keyed by the
+// authority the institution was registered with.
+export function findIssuerAddress(authority: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [ISSUER_SEED, authority.toBytes()],
+    programId
+  );
+}
+
+// Credential accounts live at PDA [b"credential", issuer account, digest], so
+// verifiers can derive the address offline instead of scanning the program.
+export function findCredentialAddress(issuer: PublicKey, digest: Uint8Array) {
+  if (digest.length !== 32) {
+    throw new Error("Credential digest must be 32 bytes");
+  }
+  return PublicKey.findProgramAddressSync(
+    [CREDENTIAL_SEED, issuer.toBytes(), digest],
+    programId
+  );
+}
+
+export function hexToDigest(hex: string) {
+  const clean = hex.startsWith("0x") ? hex.slice(2) : hex;
+  if (!/^[0-9a-fA-F]{64}$/.test(clean)) {
+    throw new Error("Credential hash must be 64 hex characters");
+  }
+  return Uint8Array.from(clean.match(/../g)!, (byte) => parseInt(byte, 16));
+}
+
+export async function getCredentialAccount(issuer: PublicKey, hash: string) {
+  const [address] = findCredentialAddress(issuer, hexToDigest(hash));
+  return await connection.getAccountInfo(address);
 }
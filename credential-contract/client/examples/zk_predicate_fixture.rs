@@ -0,0 +1,81 @@
+//! Generates the reference predicate fixture used by the TypeScript tests:
+//!
+//! `cargo run -p credential-client --example zk_predicate_fixture > tests/fixtures/zk-predicate.json`
+//!
+//! Four STEM degree credentials form the anonymity set; the holder of the
+//! second (a master's, ISCED 7) proves a level of at least bachelor's (6).
+
+use ark_std::rand::rngs::StdRng;
+use ark_std::rand::{RngCore, SeedableRng};
+use credential_client::poseidon::{field_from_bytes, field_from_u64};
+use credential_client::predicate::{self, Opening, Statement};
+
+const LEVELS: [u64; 4] = [6, 7, 5, 6];
+const HOLDER: usize = 1;
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(2025);
+    let category = field_from_bytes(b"stem");
+    let salts: Vec<[u8; 32]> = LEVELS
+        .iter()
+        .map(|_| {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            field_from_bytes(&bytes)
+        })
+        .collect();
+    let commitments = LEVELS
+        .iter()
+        .zip(&salts)
+        .map(|(level, salt)| predicate::commit(salt, &category, &field_from_u64(*level)))
+        .collect::<Option<Vec<_>>>()
+        .expect("salts and levels are field elements");
+    let statement = Statement {
+        commitments,
+        category,
+        minimum: field_from_u64(6),
+        challenge: field_from_bytes(b"acme-hiring-2025"),
+    };
+    let opening = Opening {
+        salt: salts[HOLDER],
+        value: field_from_u64(LEVELS[HOLDER]),
+    };
+
+    let proving_key = predicate::setup(LEVELS.len(), &mut rng).expect("setup");
+    let (key, ic) = predicate::verifying_key(&proving_key);
+    let proof = predicate::prove(&proving_key, &statement, &opening, &mut rng).expect("prove");
+    assert!(predicate::verify(&key, &ic, &proof, &statement));
+
+    let list = |items: &[[u8; 32]]| items.iter().map(|item| quoted(item)).collect::<Vec<_>>();
+    println!("{{");
+    println!("  \"predicateId\": \"stem-bachelor\",");
+    println!("  \"credentialCount\": {},", LEVELS.len());
+    println!("  \"key\": {{");
+    println!("    \"alphaG1\": {},", quoted(&key.alpha_g1));
+    println!("    \"betaG2\": {},", quoted(&key.beta_g2));
+    println!("    \"gammaG2\": {},", quoted(&key.gamma_g2));
+    println!("    \"deltaG2\": {}", quoted(&key.delta_g2));
+    println!("  }},");
+    let ic: Vec<_> = ic.iter().map(|point| quoted(point)).collect();
+    println!("  \"ic\": [\n    {}\n  ],", ic.join(",\n    "));
+    println!(
+        "  \"commitments\": [\n    {}\n  ],",
+        list(&statement.commitments).join(",\n    ")
+    );
+    println!(
+        "  \"parameters\": [\n    {}\n  ],",
+        list(&statement.parameters()).join(",\n    ")
+    );
+    println!("  \"challenge\": {},", quoted(&statement.challenge));
+    println!("  \"proof\": {{");
+    println!("    \"a\": {},", quoted(&proof.a));
+    println!("    \"b\": {},", quoted(&proof.b));
+    println!("    \"c\": {}", quoted(&proof.c));
+    println!("  }}");
+    println!("}}");
+}
+
+fn quoted(bytes: &[u8]) -> String {
+    let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+    format!("\"{hex}\"")
+}
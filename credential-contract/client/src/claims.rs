@@ -0,0 +1,151 @@
+//! Builds and opens the salted claim trees behind `ClaimsRoot` credentials.
+
+use credential_contract::claims::{self, claims_root, hash_claim, DisclosedClaim};
+
+use crate::MerkleTree;
+
+/// One named field of a credential document, e.g. `gpa` = `b"3.8"`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Claim {
+    pub name: String,
+    pub value: Vec<u8>,
+}
+
+/// The issuer's side of a selective-disclosure credential. Store `root()` as
+/// the credential hash with `HashAlgorithm::ClaimsRoot`, then hand the claims
+/// and salts to the holder, who uses `disclose` to open only what a verifier
+/// asks for.
+#[derive(Clone, Debug)]
+pub struct ClaimTree {
+    claims: Vec<Claim>,
+    salts: Vec<[u8; 32]>,
+    tree: MerkleTree,
+}
+
+impl ClaimTree {
+    /// `salts[i]` blinds `claims[i]`. Each must be 32 fresh random bytes kept
+    /// secret by the holder, otherwise undisclosed low-entropy values can be
+    /// guessed from the root. Returns `None` for an empty claim list or a
+    /// salt count that does not match.
+    pub fn new(claims: Vec<Claim>, salts: Vec<[u8; 32]>) -> Option<Self> {
+        if claims.len() != salts.len() {
+            return None;
+        }
+        let digests: Vec<_> = claims
+            .iter()
+            .zip(&salts)
+            .map(|(claim, salt)| hash_claim(salt, &claim.name, &claim.value))
+            .collect();
+        let tree = MerkleTree::new(&digests)?;
+        Some(Self {
+            claims,
+            salts,
+            tree,
+        })
+    }
+
+    /// The credential hash: the tree root bound to the claim count.
+    pub fn root(&self) -> [u8; 32] {
+        claims_root(&self.tree.root(), self.claim_count())
+    }
+
+    pub fn claim_count(&self) -> u32 {
+        self.tree.leaf_count()
+    }
+
+    /// Openings for the claims at `indices`, ready for `verify_claim`.
+    pub fn disclose(&self, indices: &[usize]) -> Option<Vec<DisclosedClaim>> {
+        indices
+            .iter()
+            .map(|&index| {
+                let claim = self.claims.get(index)?;
+                Some(DisclosedClaim {
+                    index: index as u32,
+                    salt: self.salts[index],
+                    name: claim.name.clone(),
+                    value: claim.value.clone(),
+                    proof: self.tree.proof(index)?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Off-chain equivalent of `verify_claim`'s proof check.
+pub fn verify_claims(root: &[u8; 32], claim_count: u32, disclosed: &[DisclosedClaim]) -> bool {
+    !disclosed.is_empty()
+        && disclosed
+            .iter()
+            .all(|claim| claims::verify(root, claim_count, claim))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(count: u8) -> ClaimTree {
+        let claims = (0..count)
+            .map(|i| Claim {
+                name: format!("claim-{i}"),
+                value: vec![i; usize::from(i) + 1],
+            })
+            .collect();
+        let salts = (0..count).map(|i| [i.wrapping_add(101); 32]).collect();
+        ClaimTree::new(claims, salts).unwrap()
+    }
+
+    #[test]
+    fn disclosed_claims_open_against_the_root() {
+        for count in 1..=9 {
+            let tree = tree(count);
+            let indices: Vec<_> = (0..usize::from(count)).collect();
+            let disclosed = tree.disclose(&indices).unwrap();
+            assert!(verify_claims(&tree.root(), tree.claim_count(), &disclosed));
+        }
+    }
+
+    #[test]
+    fn root_commits_to_the_claim_count() {
+        let tree = tree(5);
+        let disclosed = tree.disclose(&[0, 4]).unwrap();
+        for count in [3, 4, 6, 8] {
+            assert!(!verify_claims(&tree.root(), count, &disclosed));
+        }
+        assert_ne!(tree.root(), tree.tree.root());
+    }
+
+    #[test]
+    fn tampered_claims_are_rejected() {
+        let tree = tree(4);
+        let root = tree.root();
+        let original = tree.disclose(&[2]).unwrap();
+
+        let mut value = original.clone();
+        value[0].value.push(0);
+        let mut name = original.clone();
+        name[0].name = "claim-3".into();
+        let mut salt = original.clone();
+        salt[0].salt[0] ^= 1;
+        let mut index = original.clone();
+        index[0].index = 3;
+        let mut proof = original.clone();
+        proof[0].proof.pop();
+
+        for disclosed in [value, name, salt, index, proof] {
+            assert!(!verify_claims(&root, 4, &disclosed));
+        }
+        assert!(!verify_claims(&root, 4, &[]));
+        assert!(verify_claims(&root, 4, &original));
+    }
+
+    #[test]
+    fn rejects_malformed_trees_and_indices() {
+        let claim = Claim {
+            name: "gpa".into(),
+            value: b"3.8".to_vec(),
+        };
+        assert!(ClaimTree::new(vec![], vec![]).is_none());
+        assert!(ClaimTree::new(vec![claim], vec![]).is_none());
+        assert!(tree(3).disclose(&[1, 3]).is_none());
+    }
+}
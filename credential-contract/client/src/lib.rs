@@ -0,0 +1,10 @@
+//! Off-chain helpers for issuers and verifiers of `credential-contract`.
+
+pub mod claims;
+pub mod merkle;
+pub mod pda;
+pub mod poseidon;
+pub mod predicate;
+
+pub use claims::{Claim, ClaimTree};
+pub use merkle::MerkleTree;
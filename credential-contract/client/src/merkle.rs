@@ -0,0 +1,148 @@
+//! Builds the cohort trees anchored by `store_credential_batch`.
+
+use credential_contract::merkle::{hash_leaf, hash_node};
+
+/// Every level of the tree, leaves first. Layout and hashing follow
+/// `credential_contract::merkle`, so proofs produced here are accepted by
+/// `verify_in_batch`.
+#[derive(Clone, Debug)]
+pub struct MerkleTree {
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    /// Builds a tree over credential hashes in issuance order. Returns `None`
+    /// for an empty cohort.
+    pub fn new(leaves: &[[u8; 32]]) -> Option<Self> {
+        if leaves.is_empty() {
+            return None;
+        }
+
+        let mut levels = vec![leaves.iter().map(hash_leaf).collect::<Vec<_>>()];
+        while levels.last()?.len() > 1 {
+            let next = levels
+                .last()?
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_node(left, right),
+                    [carried] => *carried,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        Some(Self { levels })
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.levels[self.levels.len() - 1][0]
+    }
+
+    pub fn leaf_count(&self) -> u32 {
+        self.levels[0].len() as u32
+    }
+
+    /// Sibling hashes from the leaf at `index` up to the root, skipping
+    /// levels where the node is carried up unpaired.
+    pub fn proof(&self, index: usize) -> Option<Vec<[u8; 32]>> {
+        if index >= self.levels[0].len() {
+            return None;
+        }
+
+        let mut proof = Vec::with_capacity(self.levels.len());
+        let mut index = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = index ^ 1;
+            if sibling < level.len() {
+                proof.push(level[sibling]);
+            }
+            index /= 2;
+        }
+        Some(proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use credential_contract::merkle::compute_root;
+
+    fn leaves(count: u32) -> Vec<[u8; 32]> {
+        (0..count)
+            .map(|i| {
+                let mut leaf = [0u8; 32];
+                leaf[..4].copy_from_slice(&i.to_le_bytes());
+                leaf
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_proof_reaches_the_root() {
+        for count in 1..=69 {
+            let leaves = leaves(count);
+            let tree = MerkleTree::new(&leaves).unwrap();
+            assert_eq!(tree.leaf_count(), count);
+            for (index, leaf) in leaves.iter().enumerate() {
+                let proof = tree.proof(index).unwrap();
+                assert_eq!(
+                    compute_root(leaf, index as u32, count, &proof),
+                    Some(tree.root()),
+                    "leaf {index} of {count}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn proofs_do_not_verify_at_other_positions() {
+        for count in 2..=69 {
+            let leaves = leaves(count);
+            let tree = MerkleTree::new(&leaves).unwrap();
+            for (index, leaf) in leaves.iter().enumerate() {
+                let proof = tree.proof(index).unwrap();
+                for other in (0..count).filter(|other| *other != index as u32) {
+                    assert_ne!(
+                        compute_root(leaf, other, count, &proof),
+                        Some(tree.root()),
+                        "leaf {index} of {count} at {other}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_out_of_range_indices_and_wrong_widths() {
+        let leaves = leaves(7);
+        let tree = MerkleTree::new(&leaves).unwrap();
+        assert!(tree.proof(7).is_none());
+        assert_eq!(
+            compute_root(&leaves[6], 7, 7, &tree.proof(6).unwrap()),
+            None
+        );
+
+        // The last leaf is carried up unpaired, so its path depends on the
+        // width; batches store `leaf_count` to pin it.
+        let proof = tree.proof(6).unwrap();
+        for count in [8, 9, 12, 13] {
+            assert_ne!(
+                compute_root(&leaves[6], 6, count, &proof),
+                Some(tree.root())
+            );
+        }
+        let proof = tree.proof(3).unwrap();
+        let mut extended = proof.clone();
+        extended.push([0u8; 32]);
+        assert_eq!(compute_root(&leaves[3], 3, 7, &extended), None);
+    }
+
+    #[test]
+    fn single_leaf_root_is_the_hashed_leaf() {
+        let leaves = leaves(1);
+        let tree = MerkleTree::new(&leaves).unwrap();
+        assert_eq!(tree.root(), hash_leaf(&leaves[0]));
+        assert!(tree.proof(0).unwrap().is_empty());
+        assert!(MerkleTree::new(&[]).is_none());
+    }
+}
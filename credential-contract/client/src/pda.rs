@@ -0,0 +1,75 @@
+//! Address derivation matching the program's `#[account(seeds = ...)]`.
+
+use anchor_lang::prelude::Pubkey;
+use credential_contract::{
+    BATCH_SEED, CONFIG_SEED, CREDENTIAL_SEED, DELEGATE_SEED, ISSUER_SEED, MULTISIG_SEED,
+    PREDICATE_SEED, PROPOSAL_SEED, SCHEMA_SEED, TOMBSTONE_SEED,
+};
+
+pub fn config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED], &credential_contract::ID)
+}
+
+pub fn issuer_address(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ISSUER_SEED, authority.as_ref()], &credential_contract::ID)
+}
+
+pub fn delegate_address(issuer: &Pubkey, delegate: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DELEGATE_SEED, issuer.as_ref(), delegate.as_ref()],
+        &credential_contract::ID,
+    )
+}
+
+pub fn multisig_address(issuer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MULTISIG_SEED, issuer.as_ref()], &credential_contract::ID)
+}
+
+pub fn proposal_address(multisig: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PROPOSAL_SEED, multisig.as_ref(), &index.to_le_bytes()],
+        &credential_contract::ID,
+    )
+}
+
+/// `registry` is the owning issuer account, or the config account for
+/// program-wide schemas.
+pub fn schema_address(registry: &Pubkey, schema_id: &str, version: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SCHEMA_SEED,
+            registry.as_ref(),
+            schema_id.as_bytes(),
+            &version.to_le_bytes(),
+        ],
+        &credential_contract::ID,
+    )
+}
+
+pub fn predicate_verifier_address(predicate_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PREDICATE_SEED, predicate_id.as_bytes()],
+        &credential_contract::ID,
+    )
+}
+
+pub fn credential_address(issuer: &Pubkey, hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[CREDENTIAL_SEED, issuer.as_ref(), hash],
+        &credential_contract::ID,
+    )
+}
+
+pub fn tombstone_address(issuer: &Pubkey, hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[TOMBSTONE_SEED, issuer.as_ref(), hash],
+        &credential_contract::ID,
+    )
+}
+
+pub fn batch_address(issuer: &Pubkey, root: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BATCH_SEED, issuer.as_ref(), root],
+        &credential_contract::ID,
+    )
+}
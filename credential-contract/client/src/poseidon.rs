@@ -0,0 +1,80 @@
+//! Encodes values as BN254 field elements and computes the Poseidon
+//! commitments behind `HashAlgorithm::Poseidon` credentials, using the same
+//! code `verify_commitment_opening` runs through the `sol_poseidon` syscall.
+
+use anchor_lang::solana_program::hash::hash;
+use credential_contract::poseidon;
+
+/// `value` as a big-endian field element.
+pub fn field_from_u64(value: u64) -> [u8; 32] {
+    let mut element = [0u8; 32];
+    element[24..].copy_from_slice(&value.to_be_bytes());
+    element
+}
+
+/// SHA-256 of `bytes` cut to 253 bits, which always lies below the BN254
+/// scalar modulus. Use it for strings and documents, and to turn 32 random
+/// bytes into a salt.
+pub fn field_from_bytes(bytes: &[u8]) -> [u8; 32] {
+    let mut element = hash(bytes).to_bytes();
+    element[0] &= 0x1f;
+    element
+}
+
+/// Commitment to store as the credential hash. `salt` must be a fresh random
+/// field element kept by the holder, and at most 11 values fit in one
+/// commitment. Returns `None` for inputs that are not field elements.
+pub fn commit(salt: &[u8; 32], values: &[[u8; 32]]) -> Option<[u8; 32]> {
+    poseidon::commit(salt, values).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use credential_contract::constants::{BN254_SCALAR_MODULUS, MAX_POSEIDON_INPUTS};
+
+    fn hex(bytes: &[u8; 32]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn encodes_integers_big_endian() {
+        assert_eq!(field_from_u64(0), [0u8; 32]);
+        let element = field_from_u64(0x0102_0304_0506_0708);
+        assert_eq!(element[..24], [0u8; 24]);
+        assert_eq!(element[24..], [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn hashes_bytes_below_the_modulus() {
+        for input in [&b""[..], b"stem", b"acme-hiring-2025", &[0xff; 64]] {
+            let element = field_from_bytes(input);
+            assert!(element[..] < BN254_SCALAR_MODULUS[..]);
+            assert_eq!(element[0] & 0xe0, 0);
+            assert_eq!(element[1..], hash(input).to_bytes()[1..]);
+        }
+        assert_ne!(field_from_bytes(b"stem"), field_from_bytes(b"arts"));
+    }
+
+    #[test]
+    fn commits_like_circomlib() {
+        assert_eq!(
+            hex(&commit(&field_from_u64(1), &[field_from_u64(2)]).unwrap()),
+            "115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a"
+        );
+        assert_eq!(
+            hex(&commit(&field_from_u64(1), &[field_from_u64(2), field_from_u64(3)]).unwrap()),
+            "0e7732d89e6939c0ff03d5e58dab6302f3230e269dc5b968f725df34ab36d732"
+        );
+    }
+
+    #[test]
+    fn rejects_values_that_are_not_field_elements() {
+        let salt = field_from_bytes(b"salt");
+        assert!(commit(&salt, &[BN254_SCALAR_MODULUS]).is_none());
+        assert!(commit(&BN254_SCALAR_MODULUS, &[field_from_u64(1)]).is_none());
+        assert!(commit(&salt, &[]).is_some());
+        assert!(commit(&salt, &vec![field_from_u64(1); MAX_POSEIDON_INPUTS - 1]).is_some());
+        assert!(commit(&salt, &vec![field_from_u64(1); MAX_POSEIDON_INPUTS]).is_none());
+    }
+}
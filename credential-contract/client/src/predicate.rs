@@ -0,0 +1,345 @@
+//! Groth16 prover for the reference predicate circuit checked by
+//! `verify_zk_predicate`: "one of these credentials commits to `category`
+//! with a value of at least `minimum`", e.g. a STEM degree at bachelor's
+//! level (ISCED 6) or above, without revealing which credential.
+//!
+//! Each credential in the anonymity set is a `HashAlgorithm::Poseidon`
+//! credential whose hash is `poseidon::commit(salt, &[category, value])`.
+//! The circuit's public inputs, in order, are the set's commitments followed
+//! by `category` and `minimum`, which are registered with the predicate, and a
+//! relying-party-chosen `challenge`. `verify_zk_predicate` consumes the
+//! challenge, so a proof is accepted once.
+
+use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::{Groth16, ProvingKey};
+use ark_r1cs_std::alloc::AllocVar;
+use ark_r1cs_std::boolean::Boolean;
+use ark_r1cs_std::eq::EqGadget;
+use ark_r1cs_std::fields::fp::FpVar;
+use ark_r1cs_std::fields::FieldVar;
+use ark_r1cs_std::ToBitsGadget;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_snark::SNARK;
+use ark_std::rand::{CryptoRng, RngCore};
+use credential_contract::groth16::{self, Groth16Proof, VerifyingKey};
+use light_poseidon::parameters::bn254_x5::get_poseidon_parameters;
+
+use crate::poseidon;
+
+/// Registered predicate parameters: `category`, `minimum`.
+pub const PARAMETER_COUNT: usize = 2;
+
+/// Values are proven to lie in `[minimum, minimum + 2^VALUE_BITS)`.
+pub const VALUE_BITS: usize = 64;
+
+/// What the verifier sees and checks the proof against.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Statement {
+    /// Hashes of the credentials in the anonymity set, in the order their
+    /// accounts are passed to `verify_zk_predicate`.
+    pub commitments: Vec<[u8; 32]>,
+    pub category: [u8; 32],
+    pub minimum: [u8; 32],
+    pub challenge: [u8; 32],
+}
+
+impl Statement {
+    /// The `parameters` argument of `register_predicate_verifier`.
+    pub fn parameters(&self) -> Vec<[u8; 32]> {
+        vec![self.category, self.minimum]
+    }
+
+    fn public_inputs(&self) -> Vec<[u8; 32]> {
+        let mut inputs = self.commitments.clone();
+        inputs.extend(self.parameters());
+        inputs.push(self.challenge);
+        inputs
+    }
+}
+
+/// The holder's secret opening of one credential in the set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Opening {
+    pub salt: [u8; 32],
+    pub value: [u8; 32],
+}
+
+/// Credential hash an issuer stores for `value` under `category`.
+pub fn commit(salt: &[u8; 32], category: &[u8; 32], value: &[u8; 32]) -> Option<[u8; 32]> {
+    poseidon::commit(salt, &[*category, *value])
+}
+
+/// Runs a circuit-specific trusted setup for anonymity sets of
+/// `credential_count` credentials. Register the result of `verifying_key`
+/// with `register_predicate_verifier` using `credential_count` and
+/// `Statement::parameters`.
+pub fn setup<R: RngCore + CryptoRng>(
+    credential_count: usize,
+    rng: &mut R,
+) -> Result<ProvingKey<Bn254>, SynthesisError> {
+    let circuit = PredicateCircuit {
+        commitments: vec![Fr::from(0u64); credential_count],
+        category: Fr::from(0u64),
+        minimum: Fr::from(0u64),
+        challenge: Fr::from(0u64),
+        salt: Fr::from(0u64),
+        value: Fr::from(0u64),
+    };
+    let (proving_key, _) = Groth16::<Bn254>::circuit_specific_setup(circuit, rng)?;
+    Ok(proving_key)
+}
+
+/// The verifying key in the program's encoding: the fixed part, then the
+/// input points to upload with `extend_predicate_verifier`.
+pub fn verifying_key(proving_key: &ProvingKey<Bn254>) -> (VerifyingKey, Vec<[u8; 64]>) {
+    let vk = &proving_key.vk;
+    let key = VerifyingKey {
+        alpha_g1: g1_bytes(&vk.alpha_g1),
+        beta_g2: g2_bytes(&vk.beta_g2),
+        gamma_g2: g2_bytes(&vk.gamma_g2),
+        delta_g2: g2_bytes(&vk.delta_g2),
+    };
+    let ic = vk.gamma_abc_g1.iter().map(g1_bytes).collect();
+    (key, ic)
+}
+
+/// Proves `statement` with `opening`. Fails with `Unsatisfiable` when the
+/// opening does not match any commitment or its value is below the minimum.
+pub fn prove<R: RngCore + CryptoRng>(
+    proving_key: &ProvingKey<Bn254>,
+    statement: &Statement,
+    opening: &Opening,
+    rng: &mut R,
+) -> Result<Groth16Proof, SynthesisError> {
+    let commitment = commit(&opening.salt, &statement.category, &opening.value)
+        .ok_or(SynthesisError::Unsatisfiable)?;
+    let difference = field(&opening.value) - field(&statement.minimum);
+    if !statement.commitments.contains(&commitment)
+        || difference.into_bigint().num_bits() as usize > VALUE_BITS
+    {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+
+    let circuit = PredicateCircuit {
+        commitments: statement.commitments.iter().map(field).collect(),
+        category: field(&statement.category),
+        minimum: field(&statement.minimum),
+        challenge: field(&statement.challenge),
+        salt: field(&opening.salt),
+        value: field(&opening.value),
+    };
+    let proof = Groth16::<Bn254>::prove(proving_key, circuit, rng)?;
+    Ok(Groth16Proof {
+        a: g1_bytes(&proof.a),
+        b: g2_bytes(&proof.b),
+        c: g1_bytes(&proof.c),
+    })
+}
+
+/// Checks a proof off-chain exactly as `verify_zk_predicate` does, minus the
+/// credential status checks and the challenge's consumption.
+pub fn verify(
+    key: &VerifyingKey,
+    ic: &[[u8; 64]],
+    proof: &Groth16Proof,
+    statement: &Statement,
+) -> bool {
+    groth16::verify(key, ic, proof, &statement.public_inputs()).is_ok()
+}
+
+struct PredicateCircuit {
+    commitments: Vec<Fr>,
+    category: Fr,
+    minimum: Fr,
+    challenge: Fr,
+    salt: Fr,
+    value: Fr,
+}
+
+impl ConstraintSynthesizer<Fr> for PredicateCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let commitments = self
+            .commitments
+            .iter()
+            .map(|commitment| FpVar::new_input(cs.clone(), || Ok(*commitment)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let category = FpVar::new_input(cs.clone(), || Ok(self.category))?;
+        let minimum = FpVar::new_input(cs.clone(), || Ok(self.minimum))?;
+        // Groth16 binds every public input, so the challenge needs no
+        // constraint of its own.
+        let _challenge = FpVar::new_input(cs.clone(), || Ok(self.challenge))?;
+        let salt = FpVar::new_witness(cs.clone(), || Ok(self.salt))?;
+        let value = FpVar::new_witness(cs, || Ok(self.value))?;
+
+        // The opened commitment is one of the set: the product of the
+        // differences vanishes.
+        let commitment = poseidon_var(&[salt, category, value.clone()])?;
+        let mut product = FpVar::one();
+        for candidate in &commitments {
+            product *= candidate - &commitment;
+        }
+        product.enforce_equal(&FpVar::zero())?;
+
+        // `value - minimum` fits in `VALUE_BITS` bits, so it did not wrap
+        // around the field and `value >= minimum`.
+        let difference = (value - minimum).to_bits_le()?;
+        for bit in &difference[VALUE_BITS..] {
+            bit.enforce_equal(&Boolean::FALSE)?;
+        }
+        Ok(())
+    }
+}
+
+/// Poseidon over BN254 as constraints, with the circomlib parameters the
+/// `sol_poseidon` syscall uses.
+fn poseidon_var(inputs: &[FpVar<Fr>]) -> Result<FpVar<Fr>, SynthesisError> {
+    let width = inputs.len() + 1;
+    let params =
+        get_poseidon_parameters::<Fr>(width as u8).map_err(|_| SynthesisError::Unsatisfiable)?;
+    let half_full = params.full_rounds / 2;
+
+    let mut state = vec![FpVar::zero()];
+    state.extend_from_slice(inputs);
+    for round in 0..params.full_rounds + params.partial_rounds {
+        for (i, element) in state.iter_mut().enumerate() {
+            *element += params.ark[round * width + i];
+        }
+        let full = round < half_full || round >= half_full + params.partial_rounds;
+        let sbox_len = if full { width } else { 1 };
+        for element in &mut state[..sbox_len] {
+            let squared = element.square()?;
+            *element = squared.square()? * &*element;
+        }
+        state = (0..width)
+            .map(|i| {
+                state
+                    .iter()
+                    .zip(&params.mds[i])
+                    .fold(FpVar::zero(), |acc, (element, m)| acc + element * *m)
+            })
+            .collect();
+    }
+    Ok(state.swap_remove(0))
+}
+
+fn field(bytes: &[u8; 32]) -> Fr {
+    Fr::from_be_bytes_mod_order(bytes)
+}
+
+fn g1_bytes(point: &G1Affine) -> [u8; 64] {
+    let mut bytes = [0u8; 64];
+    if let Some((x, y)) = point.xy() {
+        bytes[..32].copy_from_slice(&x.into_bigint().to_bytes_be());
+        bytes[32..].copy_from_slice(&y.into_bigint().to_bytes_be());
+    }
+    bytes
+}
+
+fn g2_bytes(point: &G2Affine) -> [u8; 128] {
+    let mut bytes = [0u8; 128];
+    if let Some((x, y)) = point.xy() {
+        for (chunk, coordinate) in bytes.chunks_mut(32).zip([x.c1, x.c0, y.c1, y.c0]) {
+            chunk.copy_from_slice(&coordinate.into_bigint().to_bytes_be());
+        }
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poseidon::{field_from_bytes, field_from_u64};
+    use ark_std::rand::rngs::StdRng;
+    use ark_std::rand::SeedableRng;
+
+    const LEVELS: [u64; 3] = [6, 7, 5];
+
+    fn salt(i: u8) -> [u8; 32] {
+        field_from_bytes(&[i; 16])
+    }
+
+    fn statement() -> Statement {
+        let category = field_from_bytes(b"stem");
+        Statement {
+            commitments: LEVELS
+                .iter()
+                .zip(0..)
+                .map(|(level, i)| commit(&salt(i), &category, &field_from_u64(*level)).unwrap())
+                .collect(),
+            category,
+            minimum: field_from_u64(6),
+            challenge: field_from_bytes(b"challenge"),
+        }
+    }
+
+    fn opening(i: u8) -> Opening {
+        Opening {
+            salt: salt(i),
+            value: field_from_u64(LEVELS[usize::from(i)]),
+        }
+    }
+
+    #[test]
+    fn proves_and_verifies_a_valid_opening() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let proving_key = setup(LEVELS.len(), &mut rng).unwrap();
+        let (key, ic) = verifying_key(&proving_key);
+        assert_eq!(ic.len(), LEVELS.len() + PARAMETER_COUNT + 2);
+
+        let statement = statement();
+        for holder in [0, 1] {
+            let proof = prove(&proving_key, &statement, &opening(holder), &mut rng).unwrap();
+            assert!(verify(&key, &ic, &proof, &statement));
+        }
+    }
+
+    #[test]
+    fn unsatisfiable_openings_are_not_proven() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let proving_key = setup(LEVELS.len(), &mut rng).unwrap();
+        let statement = statement();
+
+        // Committed, but below the minimum.
+        let below = opening(2);
+        // At the minimum, but under a salt no credential in the set used.
+        let outside = Opening {
+            salt: salt(9),
+            ..opening(0)
+        };
+        for opening in [below, outside] {
+            assert!(matches!(
+                prove(&proving_key, &statement, &opening, &mut rng),
+                Err(SynthesisError::Unsatisfiable)
+            ));
+        }
+    }
+
+    #[test]
+    fn proofs_are_bound_to_the_challenge_and_parameters() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let proving_key = setup(LEVELS.len(), &mut rng).unwrap();
+        let (key, ic) = verifying_key(&proving_key);
+        let statement = statement();
+        let proof = prove(&proving_key, &statement, &opening(1), &mut rng).unwrap();
+
+        let altered = [
+            Statement {
+                challenge: field_from_bytes(b"another challenge"),
+                ..statement.clone()
+            },
+            Statement {
+                category: field_from_bytes(b"arts"),
+                ..statement.clone()
+            },
+            Statement {
+                minimum: field_from_u64(5),
+                ..statement.clone()
+            },
+        ];
+        for statement in &altered {
+            assert!(!verify(&key, &ic, &proof, statement));
+        }
+    }
+}
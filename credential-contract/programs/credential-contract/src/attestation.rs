@@ -0,0 +1,233 @@
+//! Issuer attestations: signatures by an institution's own key over the
+//! credential it issues, checked on-chain by introspecting the signature
+//! precompile instruction that precedes the issuing instruction.
+//!
+//! Ed25519 signing keys sign a domain-separated, fixed-length message, so the
+//! same signature can be re-verified offline from the `Credential` account:
+//!
+//! `ATTESTATION_DOMAIN || program id || issuer || schema || hash || subject`
+//!
+//! where `subject` is `Pubkey::default()` when the credential has none.
+//! Ethereum issuers sign an extended form of it; see `eth_credential_message`.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::solana_program::{ed25519_program, secp256k1_program};
+
+use crate::constants::ATTESTATION_DOMAIN;
+use crate::error::CredentialError;
+use crate::state::{CredentialTerms, Issuer, IssuerAttestation};
+
+/// Bytes the issuer's signing key signs for a credential with these terms.
+pub fn credential_message(
+    issuer: &Pubkey,
+    schema: &Pubkey,
+    hash: &[u8; 32],
+    subject: Option<&Pubkey>,
+) -> Vec<u8> {
+    let subject = subject.copied().unwrap_or_default();
+    [
+        ATTESTATION_DOMAIN,
+        crate::ID.as_ref(),
+        issuer.as_ref(),
+        schema.as_ref(),
+        hash.as_ref(),
+        subject.as_ref(),
+    ]
+    .concat()
+}
+
+/// Message an Ethereum issuer signs for relayed issuance. Nobody with a
+/// Solana key vouches for the transaction, so it also commits to every term
+/// the relayer could otherwise choose: `credential_message` followed by the
+/// Borsh encoding of `(algorithm, valid_from, valid_until, metadata)` and,
+/// for a reissue, the address of the credential it `supersedes`, all wrapped
+/// in the EIP-191 `personal_sign` envelope wallets produce.
+pub fn eth_credential_message(
+    issuer: &Pubkey,
+    terms: &CredentialTerms,
+    supersedes: Option<&Pubkey>,
+) -> Result<Vec<u8>> {
+    let mut message =
+        credential_message(issuer, &terms.schema, &terms.hash, terms.subject.as_ref());
+    message.extend(borsh::to_vec(&(
+        terms.algorithm,
+        terms.valid_from,
+        terms.valid_until,
+        &terms.metadata,
+    ))?);
+    if let Some(supersedes) = supersedes {
+        message.extend_from_slice(supersedes.as_ref());
+    }
+    let mut envelope = format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+    envelope.extend(message);
+    Ok(envelope)
+}
+
+/// Returns the attestation to record on a new credential, taken from the
+/// signature precompile instruction immediately before the current one:
+/// Ed25519 over `credential_message` by the issuer's `signing_key`, or
+/// secp256k1 over `eth_credential_message` by its `eth_address`. Issuers
+/// with a signing key must provide the Ed25519 one: an Ethereum address can
+/// stand in for the authority, but never for the signing key.
+pub(crate) fn require_attestation(
+    issuer: &Account<Issuer>,
+    instructions: Option<&AccountInfo>,
+    terms: &CredentialTerms,
+    supersedes: Option<&Pubkey>,
+) -> Result<Option<IssuerAttestation>> {
+    let attestation = match instructions {
+        Some(instructions) => find_attestation(issuer, instructions, terms, supersedes)?,
+        None => None,
+    };
+    require!(
+        issuer.signing_key.is_none()
+            || matches!(attestation, Some(IssuerAttestation::Ed25519 { .. })),
+        CredentialError::MissingIssuerSignature
+    );
+    Ok(attestation)
+}
+
+fn find_attestation(
+    issuer: &Account<Issuer>,
+    instructions: &AccountInfo,
+    terms: &CredentialTerms,
+    supersedes: Option<&Pubkey>,
+) -> Result<Option<IssuerAttestation>> {
+    let Some(index) = load_current_index_checked(instructions)?.checked_sub(1) else {
+        return Ok(None);
+    };
+    let ix = load_instruction_at_checked(usize::from(index), instructions)?;
+
+    if ix.program_id == ed25519_program::ID {
+        let Some(signing_key) = issuer.signing_key else {
+            return Ok(None);
+        };
+        let message = credential_message(
+            &issuer.key(),
+            &terms.schema,
+            &terms.hash,
+            terms.subject.as_ref(),
+        );
+        let signature = verify_ed25519(&ix.data, index, &signing_key, &message)?;
+        return Ok(Some(IssuerAttestation::Ed25519 {
+            signing_key,
+            signature,
+        }));
+    }
+    if ix.program_id == secp256k1_program::ID {
+        let Some(eth_address) = issuer.eth_address else {
+            return Ok(None);
+        };
+        let message = eth_credential_message(&issuer.key(), terms, supersedes)?;
+        let (signature, recovery_id) = verify_secp256k1(&ix.data, index, &eth_address, &message)?;
+        return Ok(Some(IssuerAttestation::Secp256k1 {
+            eth_address,
+            signature,
+            recovery_id,
+        }));
+    }
+    Ok(None)
+}
+
+/// Layout of one entry in an Ed25519 precompile instruction, after the
+/// leading signature count and padding bytes.
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+
+/// Checks that the Ed25519 precompile instruction at `index`, with data
+/// `data`, verified `signing_key`'s signature over exactly `message`, and
+/// returns that signature.
+fn verify_ed25519(
+    data: &[u8],
+    index: u16,
+    signing_key: &Pubkey,
+    message: &[u8],
+) -> Result<[u8; 64]> {
+    require!(
+        data.len() >= ED25519_OFFSETS_START + ED25519_OFFSETS_LEN && data[0] == 1,
+        CredentialError::InvalidIssuerSignature
+    );
+    let field = |n: usize| {
+        let at = ED25519_OFFSETS_START + 2 * n;
+        u16::from_le_bytes([data[at], data[at + 1]])
+    };
+    // Every offset must point into the precompile instruction itself, not
+    // into some other instruction the caller controls.
+    for ix_index in [field(1), field(3), field(6)] {
+        require!(
+            ix_index == u16::MAX || ix_index == index,
+            CredentialError::InvalidIssuerSignature
+        );
+    }
+    let slice = |offset: u16, len: usize| {
+        let start = usize::from(offset);
+        data.get(start..start + len)
+            .ok_or(CredentialError::InvalidIssuerSignature)
+    };
+
+    require!(
+        slice(field(2), 32)? == signing_key.as_ref(),
+        CredentialError::InvalidIssuerSignature
+    );
+    require!(
+        usize::from(field(5)) == message.len() && slice(field(4), message.len())? == message,
+        CredentialError::InvalidIssuerSignature
+    );
+    let mut signature = [0u8; 64];
+    signature.copy_from_slice(slice(field(0), 64)?);
+    Ok(signature)
+}
+
+/// Layout of one entry in a secp256k1 precompile instruction, after the
+/// leading signature count byte: u16 offsets with u8 instruction indices.
+const SECP256K1_OFFSETS_START: usize = 1;
+const SECP256K1_OFFSETS_LEN: usize = 11;
+
+/// Checks that the secp256k1 precompile instruction at `index` recovered
+/// `eth_address` from a signature over exactly `message`, and returns the
+/// signature and its recovery id.
+fn verify_secp256k1(
+    data: &[u8],
+    index: u16,
+    eth_address: &[u8; 20],
+    message: &[u8],
+) -> Result<([u8; 64], u8)> {
+    require!(
+        data.len() >= SECP256K1_OFFSETS_START + SECP256K1_OFFSETS_LEN && data[0] == 1,
+        CredentialError::InvalidIssuerSignature
+    );
+    let offsets = &data[SECP256K1_OFFSETS_START..SECP256K1_OFFSETS_START + SECP256K1_OFFSETS_LEN];
+    let u16_at = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+    let (signature_offset, signature_ix) = (u16_at(0), offsets[2]);
+    let (address_offset, address_ix) = (u16_at(3), offsets[5]);
+    let (message_offset, message_size, message_ix) = (u16_at(6), u16_at(8), offsets[10]);
+    for ix_index in [signature_ix, address_ix, message_ix] {
+        require!(
+            u16::from(ix_index) == index,
+            CredentialError::InvalidIssuerSignature
+        );
+    }
+    let slice = |offset: u16, len: usize| {
+        let start = usize::from(offset);
+        data.get(start..start + len)
+            .ok_or(CredentialError::InvalidIssuerSignature)
+    };
+
+    require!(
+        slice(address_offset, 20)? == eth_address.as_ref(),
+        CredentialError::InvalidIssuerSignature
+    );
+    require!(
+        usize::from(message_size) == message.len()
+            && slice(message_offset, message.len())? == message,
+        CredentialError::InvalidIssuerSignature
+    );
+    // The recovery id follows the 64-byte signature.
+    let signed = slice(signature_offset, 65)?;
+    let mut signature = [0u8; 64];
+    signature.copy_from_slice(&signed[..64]);
+    Ok((signature, signed[64]))
+}
@@ -0,0 +1,55 @@
+//! Selective disclosure for `HashAlgorithm::ClaimsRoot` credentials.
+//!
+//! The credential hash commits to a `crate::merkle` tree whose leaves are
+//! individually salted claim digests, in the order the issuer listed them,
+//! and to how many there are:
+//!
+//! `claim digest = SHA-256(salt || name length as u32 LE || name || value)`
+//!
+//! `credential hash = SHA-256(0x02 || claim count as u32 LE || tree root)`
+//!
+//! Without the count, the same root could be opened as a tree of another
+//! width, which changes which position a proof lands on.
+//!
+//! A holder reveals a claim by handing over its salt, name, value and Merkle
+//! path; the other claims stay hidden behind their own salts.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::constants::MAX_MERKLE_DEPTH;
+use crate::merkle;
+
+/// One claim opened against a claims root, as passed to `verify_claim`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct DisclosedClaim {
+    /// Position of the claim in the issuer's claim list.
+    pub index: u32,
+    pub salt: [u8; 32],
+    pub name: String,
+    pub value: Vec<u8>,
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// Prefix of the credential hash, distinct from the `crate::merkle` leaf and
+/// node prefixes.
+pub const CLAIMS_ROOT_PREFIX: &[u8] = &[0x02];
+
+pub fn hash_claim(salt: &[u8; 32], name: &str, value: &[u8]) -> [u8; 32] {
+    let name_len = (name.len() as u32).to_le_bytes();
+    hashv(&[salt, &name_len, name.as_bytes(), value]).to_bytes()
+}
+
+/// Credential hash for a claim tree with root `tree_root`.
+pub fn claims_root(tree_root: &[u8; 32], claim_count: u32) -> [u8; 32] {
+    hashv(&[CLAIMS_ROOT_PREFIX, &claim_count.to_le_bytes(), tree_root]).to_bytes()
+}
+
+/// Whether `claim` is committed to by `root`, the credential hash of a tree
+/// of `claim_count` claims.
+pub fn verify(root: &[u8; 32], claim_count: u32, claim: &DisclosedClaim) -> bool {
+    let digest = hash_claim(&claim.salt, &claim.name, &claim.value);
+    claim.proof.len() <= MAX_MERKLE_DEPTH
+        && merkle::compute_root(&digest, claim.index, claim_count, &claim.proof)
+            .is_some_and(|tree_root| claims_root(&tree_root, claim_count) == *root)
+}
@@ -0,0 +1,95 @@
+/// Seed of the singleton `Config` PDA.
+pub const CONFIG_SEED: &[u8] = b"config";
+
+/// Account layout version written to `Config::version` by `initialize`.
+pub const CONFIG_VERSION: u8 = 1;
+
+/// Seed prefix for `Issuer` PDAs: `[ISSUER_SEED, authority at registration]`.
+pub const ISSUER_SEED: &[u8] = b"issuer";
+
+/// Seed prefix for `IssuerDelegate` PDAs: `[DELEGATE_SEED, issuer account, delegate]`.
+pub const DELEGATE_SEED: &[u8] = b"delegate";
+
+/// Seed prefix for `IssuerMultisig` PDAs: `[MULTISIG_SEED, issuer account]`.
+pub const MULTISIG_SEED: &[u8] = b"multisig";
+
+/// Seed prefix for `Proposal` PDAs: `[PROPOSAL_SEED, multisig, index as u64 LE]`.
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+
+/// Most leaf positions one `MultisigAction::SetRevocationBits` proposal can
+/// name; larger updates are split across proposals.
+pub const MAX_PROPOSAL_REVOCATION_INDICES: usize = 64;
+
+/// Seed prefix for `CredentialSchema` PDAs:
+/// `[SCHEMA_SEED, issuer or config account, schema_id, version as u16 LE]`.
+pub const SCHEMA_SEED: &[u8] = b"schema";
+
+/// Seed prefix for `PredicateVerifier` PDAs: `[PREDICATE_SEED, predicate_id]`.
+pub const PREDICATE_SEED: &[u8] = b"predicate";
+
+/// Seed prefix for `PredicateNullifier` PDAs:
+/// `[PREDICATE_NULLIFIER_SEED, verifier, challenge]`.
+pub const PREDICATE_NULLIFIER_SEED: &[u8] = b"predicate_nullifier";
+
+/// Seed prefix for `Credential` PDAs: `[CREDENTIAL_SEED, issuer account, hash]`.
+pub const CREDENTIAL_SEED: &[u8] = b"credential";
+
+/// Seed prefix for `Tombstone` PDAs: `[TOMBSTONE_SEED, issuer account, hash]`.
+pub const TOMBSTONE_SEED: &[u8] = b"tombstone";
+
+/// Seed prefix for `CredentialBatch` PDAs: `[BATCH_SEED, issuer account, root]`.
+pub const BATCH_SEED: &[u8] = b"batch";
+
+/// Seed prefix for `RevocationList` PDAs: `[REVOCATION_LIST_SEED, batch]`.
+pub const REVOCATION_LIST_SEED: &[u8] = b"revocation_list";
+
+/// Upper bound on leaves per batch. Keeps proofs within `MAX_MERKLE_DEPTH` and
+/// the batch's 8 KiB revocation bitmap within a single account allocation.
+pub const MAX_BATCH_LEAVES: u32 = 1 << 16;
+
+/// Longest inclusion proof `verify_in_batch` will walk.
+pub const MAX_MERKLE_DEPTH: usize = 16;
+
+pub const MAX_ISSUER_NAME_LEN: usize = 64;
+pub const MAX_ISSUER_WEBSITE_LEN: usize = 128;
+pub const MAX_ISSUER_DID_LEN: usize = 128;
+
+/// Schema ids are used as a PDA seed, which caps them at 32 bytes.
+pub const MAX_SCHEMA_ID_LEN: usize = 32;
+pub const MAX_SCHEMA_NAME_LEN: usize = 64;
+pub const MAX_SCHEMA_URI_LEN: usize = 200;
+
+/// Predicate ids are used as a PDA seed, which caps them at 32 bytes.
+pub const MAX_PREDICATE_ID_LEN: usize = 32;
+
+/// Most public inputs, credential commitments included, a predicate circuit
+/// may take.
+pub const MAX_PREDICATE_INPUTS: usize = 16;
+
+/// Longest off-chain document reference: IPFS CID, Arweave id or HTTPS URL.
+pub const MAX_METADATA_URI_LEN: usize = 200;
+
+/// Prefix of the message an issuer signing key signs; see `crate::attestation`.
+pub const ATTESTATION_DOMAIN: &[u8] = b"blockverify:credential:v1";
+
+/// Largest signer set an `IssuerMultisig` can hold.
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+/// Retired authorities kept on an `Issuer`; once it is full, each rotation
+/// drops the oldest record.
+pub const MAX_AUTHORITY_HISTORY: usize = 16;
+
+/// Inputs the `sol_poseidon` syscall accepts in one hash.
+pub const MAX_POSEIDON_INPUTS: usize = 12;
+
+/// BN254 scalar field modulus, big-endian. Poseidon digests must be below it.
+pub const BN254_SCALAR_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// BN254 base field modulus, big-endian. Negates G1 points for Groth16.
+pub const BN254_BASE_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
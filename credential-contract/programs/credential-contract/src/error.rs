@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+
+/// Every failure the program raises on its own. Anchor numbers these from
+/// 6000 in declaration order, so once deployed new variants must be appended.
+#[error_code]
+pub enum CredentialError {
+    // Program administration.
+    #[msg("Signer is not the program admin")]
+    NotAdmin,
+    #[msg("Only the program upgrade authority can initialize the config")]
+    NotUpgradeAuthority,
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin,
+    #[msg("Program is paused")]
+    ProgramPaused,
+
+    // Issuer registry.
+    #[msg("Signer is not the authority of a registered issuer")]
+    UnregisteredIssuer,
+    #[msg("Issuer is not active")]
+    IssuerNotActive,
+    #[msg("Issuer name, website or DID exceeds its maximum length")]
+    IssuerFieldTooLong,
+    #[msg("Only the issuer authority or the program admin can rotate the issuer key")]
+    RotationNotAuthorized,
+    #[msg("New authority must differ from the current one")]
+    SameAuthority,
+    #[msg("Only the issuer authority or the program admin can change this setting")]
+    NotIssuerAuthorityOrAdmin,
+    #[msg("Issuer requires a signature instruction before issuance")]
+    MissingIssuerSignature,
+    #[msg("Signature instruction does not cover this credential with the issuer's key")]
+    InvalidIssuerSignature,
+    #[msg("Issuer has a signing key, which cannot sign for the credentials of a batch")]
+    SigningKeyPreventsBatch,
+    #[msg("Signer is not an authorized delegate of this issuer")]
+    UnauthorizedDelegate,
+    #[msg("Delegate authorization has expired")]
+    DelegateExpired,
+    #[msg("Delegate has reached its issuance limit")]
+    DelegateQuotaExceeded,
+    #[msg("Delegate may not issue credentials of this schema")]
+    DelegateSchemaNotAllowed,
+    #[msg("Delegate was appointed before the issuer came under multisig governance")]
+    DelegatePredatesMultisig,
+    #[msg("Delegate was appointed by a retired issuer authority")]
+    DelegatePredatesAuthority,
+
+    // Schema registry.
+    #[msg("Schema id must be 1-32 bytes and name and URI within their maximum lengths")]
+    SchemaFieldTooLong,
+    #[msg("Schema belongs to another issuer")]
+    SchemaNotAvailable,
+
+    // Multisig governance.
+    #[msg(
+        "Multisig needs 1 to 10 distinct signers and a threshold between 1 and the signer count"
+    )]
+    InvalidMultisigConfig,
+    #[msg("Signer is not a member of the issuer multisig")]
+    NotMultisigSigner,
+    #[msg("Signer has already approved this proposal")]
+    AlreadyApproved,
+    #[msg("Only the proposer can cancel a proposal")]
+    NotProposer,
+    #[msg("Proposal has not reached the approval threshold")]
+    InsufficientApprovals,
+    #[msg("Account does not match the one the proposed action targets")]
+    ActionAccountMismatch,
+    #[msg("Account the proposed action would create already exists")]
+    ActionAccountInUse,
+    #[msg("Issuer is governed by a multisig; act through its proposals")]
+    IssuerMultisigGoverned,
+
+    // Credential input.
+    #[msg("Credential hash must be a non-zero 32-byte digest")]
+    InvalidHash,
+    #[msg("Poseidon digest is not a canonical BN254 field element")]
+    InvalidFieldElement,
+    #[msg("This issuer has already used this credential hash")]
+    DuplicateCredential,
+    #[msg("Validity window must end after it starts and after the current time")]
+    InvalidValidityWindow,
+    #[msg("Metadata URI must be 1-200 bytes with a non-zero content digest")]
+    InvalidMetadata,
+    #[msg("Batch must contain between 1 and 2^16 credentials")]
+    InvalidBatchSize,
+    #[msg("Merkle proof is deeper than any supported batch")]
+    InvalidMerkleProof,
+    #[msg("Leaf index is outside the batch")]
+    InvalidLeafIndex,
+
+    // Credential state.
+    #[msg("Credential was not issued by this issuer")]
+    IssuerMismatch,
+    #[msg("Credential has been revoked")]
+    CredentialRevoked,
+    #[msg("Credential is suspended")]
+    CredentialSuspended,
+    #[msg("Credential is not suspended")]
+    CredentialNotSuspended,
+    #[msg("Credential has been superseded by a reissued version")]
+    CredentialSuperseded,
+    #[msg("Credential is not valid yet")]
+    CredentialNotYetValid,
+    #[msg("Credential has expired")]
+    CredentialExpired,
+    #[msg("Credential has no expiry to renew")]
+    NotExpiring,
+    #[msg("Credential is linked to a reissued version and cannot be closed")]
+    CredentialInSupersessionChain,
+
+    // Verification.
+    #[msg("No credential exists at this address")]
+    CredentialNotFound,
+    #[msg("Credential has been closed by its issuer")]
+    CredentialClosed,
+    #[msg("Credential was issued to a different subject")]
+    SubjectMismatch,
+    #[msg("Credential was issued under a different schema")]
+    SchemaMismatch,
+    #[msg("Credential hash is not a claims root")]
+    NotClaimsCommitment,
+    #[msg("Disclosed claim does not open against the credential's claims root")]
+    InvalidClaimProof,
+    #[msg("Credential hash is not a Poseidon commitment")]
+    NotPoseidonCommitment,
+    #[msg("Salt and values do not open the credential's Poseidon commitment")]
+    InvalidCommitmentOpening,
+    #[msg("Credential program did not return a verification result")]
+    MissingVerificationResult,
+
+    // Zero-knowledge predicates.
+    #[msg("Predicate id, input counts or verifying key points are invalid")]
+    InvalidVerifyingKey,
+    #[msg("Predicate verifying key has not been fully uploaded")]
+    VerifyingKeyIncomplete,
+    #[msg("Credentials or public inputs do not match the predicate's circuit")]
+    PredicateInputMismatch,
+    #[msg("Credential in the anonymity set is not currently valid")]
+    PredicateCredentialNotValid,
+    #[msg("Zero-knowledge proof does not verify")]
+    InvalidZkProof,
+}
@@ -0,0 +1,282 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    CredentialMetadata, DelegateScope, HashAlgorithm, IssuerAttestation, IssuerStatus,
+    MultisigAction, RevocationReason,
+};
+
+#[event]
+pub struct ConfigInitialized {
+    pub admin: Pubkey,
+    pub version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminNominated {
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseChanged {
+    pub admin: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerRegistered {
+    pub issuer: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub registered_at: i64,
+}
+
+#[event]
+pub struct IssuerStatusChanged {
+    pub issuer: Pubkey,
+    pub previous_status: IssuerStatus,
+    pub status: IssuerStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerAuthorityRotated {
+    pub issuer: Pubkey,
+    pub previous_authority: Pubkey,
+    pub authority: Pubkey,
+    pub rotated_by: Pubkey,
+    pub effective_from_slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerSigningKeyChanged {
+    pub issuer: Pubkey,
+    pub previous_signing_key: Option<Pubkey>,
+    pub signing_key: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerEthAddressChanged {
+    pub issuer: Pubkey,
+    pub previous_eth_address: Option<[u8; 20]>,
+    pub eth_address: Option<[u8; 20]>,
+    pub changed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DelegateAdded {
+    pub issuer: Pubkey,
+    pub delegate: Pubkey,
+    pub scope: DelegateScope,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DelegateRemoved {
+    pub issuer: Pubkey,
+    pub delegate: Pubkey,
+    pub issued_count: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerMultisigCreated {
+    pub issuer: Pubkey,
+    pub multisig: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigSignersChanged {
+    pub issuer: Pubkey,
+    pub multisig: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ActionProposed {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: MultisigAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ActionApproved {
+    pub proposal: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ActionCancelled {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ActionExecuted {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub action: MultisigAction,
+    pub executed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SchemaRegistered {
+    pub schema: Pubkey,
+    pub registry: Pubkey,
+    pub schema_id: String,
+    pub version: u16,
+    pub content_hash: [u8; 32],
+    pub uri: String,
+    pub registered_at: i64,
+}
+
+#[event]
+pub struct PredicateVerifierRegistered {
+    pub verifier: Pubkey,
+    pub predicate_id: String,
+    pub schema: Pubkey,
+    pub credential_count: u8,
+    pub input_count: u8,
+    pub parameters: Vec<[u8; 32]>,
+    pub registered_at: i64,
+}
+
+#[event]
+pub struct PredicateVerifierExtended {
+    pub verifier: Pubkey,
+    pub ic_len: u8,
+    pub complete: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ZkPredicateVerified {
+    pub verifier: Pubkey,
+    pub challenge: [u8; 32],
+    pub credentials: Vec<Pubkey>,
+    pub verified_by: Pubkey,
+    pub verified_at: i64,
+}
+
+#[event]
+pub struct CredentialIssued {
+    pub credential: Pubkey,
+    pub issuer: Pubkey,
+    pub issued_by: Pubkey,
+    pub schema: Pubkey,
+    pub subject: Option<Pubkey>,
+    pub hash: [u8; 32],
+    pub algorithm: HashAlgorithm,
+    pub metadata: Option<CredentialMetadata>,
+    pub attestation: Option<IssuerAttestation>,
+    pub valid_from: Option<i64>,
+    pub valid_until: Option<i64>,
+    /// Earlier credential this one replaces, when issued by `reissue_credential`.
+    pub supersedes: Option<Pubkey>,
+    pub issued_at: i64,
+}
+
+#[event]
+pub struct CredentialBatchIssued {
+    pub batch: Pubkey,
+    pub issuer: Pubkey,
+    pub issued_by: Pubkey,
+    pub root: [u8; 32],
+    pub leaf_count: u32,
+    pub algorithm: HashAlgorithm,
+    pub issued_at: i64,
+}
+
+#[event]
+pub struct BatchCredentialsRevoked {
+    pub batch: Pubkey,
+    pub issuer: Pubkey,
+    /// Leaf positions named in the instruction, including any already revoked.
+    pub indices: Vec<u32>,
+    pub revoked_count: u32,
+    pub revoked_at: i64,
+}
+
+#[event]
+pub struct CredentialRenewed {
+    pub credential: Pubkey,
+    pub issuer: Pubkey,
+    pub hash: [u8; 32],
+    pub previous_valid_until: i64,
+    pub valid_until: i64,
+    pub renewal_count: u16,
+    pub renewed_at: i64,
+}
+
+#[event]
+pub struct CredentialSuspended {
+    pub credential: Pubkey,
+    pub issuer: Pubkey,
+    pub hash: [u8; 32],
+    pub suspended_at: i64,
+}
+
+#[event]
+pub struct CredentialReinstated {
+    pub credential: Pubkey,
+    pub issuer: Pubkey,
+    pub hash: [u8; 32],
+    pub reinstated_at: i64,
+}
+
+#[event]
+pub struct CredentialClosed {
+    pub credential: Pubkey,
+    pub issuer: Pubkey,
+    pub hash: [u8; 32],
+    pub recipient: Pubkey,
+    pub closed_at: i64,
+}
+
+#[event]
+pub struct CredentialSuperseded {
+    pub credential: Pubkey,
+    pub issuer: Pubkey,
+    pub hash: [u8; 32],
+    pub superseded_by: Pubkey,
+    pub superseded_at: i64,
+}
+
+#[event]
+pub struct CredentialRevoked {
+    pub credential: Pubkey,
+    pub issuer: Pubkey,
+    pub hash: [u8; 32],
+    pub reason: RevocationReason,
+    pub revoked_by: Pubkey,
+    pub revoked_at: i64,
+}
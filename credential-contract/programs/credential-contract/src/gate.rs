@@ -0,0 +1,88 @@
+//! Helpers for programs that gate their own instructions on a credential.
+//!
+//! A consumer depends on this crate with the `cpi` feature, passes the
+//! issuer account, the credential and tombstone PDAs and the credential
+//! program through its own accounts, and calls [`require_valid_credential`]:
+//!
+//! ```ignore
+//! let result = credential_contract::gate::require_valid_credential(
+//!     ctx.accounts.credential_program.to_account_info(),
+//!     ctx.accounts.issuer.to_account_info(),
+//!     ctx.accounts.credential.to_account_info(),
+//!     ctx.accounts.tombstone.to_account_info(),
+//!     hash,
+//!     Some(ctx.accounts.member.key()),
+//!     Some(ctx.accounts.gate.schema),
+//! )?;
+//! ```
+//!
+//! The issuer is pinned by the credential's PDA seeds, so a consumer that
+//! only trusts one institution should constrain the issuer account it accepts.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::get_return_data;
+
+use crate::error::CredentialError;
+use crate::state::VerificationResult;
+
+/// Invokes `verify_credential` and decodes the `VerificationResult` it returns.
+pub fn verify_credential<'info>(
+    credential_program: AccountInfo<'info>,
+    issuer: AccountInfo<'info>,
+    credential: AccountInfo<'info>,
+    tombstone: AccountInfo<'info>,
+    hash: [u8; 32],
+) -> Result<VerificationResult> {
+    let cpi_ctx = CpiContext::new(
+        credential_program,
+        crate::cpi::accounts::VerifyCredential {
+            issuer,
+            credential,
+            tombstone,
+        },
+    );
+    crate::cpi::verify_credential(cpi_ctx, hash)?;
+    read_verification_result()
+}
+
+/// Like [`verify_credential`], but fails unless the credential is valid and,
+/// when given, was issued to `subject` under the `CredentialSchema` at
+/// `schema`. Batch leaves carry no schema and never match one.
+pub fn require_valid_credential<'info>(
+    credential_program: AccountInfo<'info>,
+    issuer: AccountInfo<'info>,
+    credential: AccountInfo<'info>,
+    tombstone: AccountInfo<'info>,
+    hash: [u8; 32],
+    subject: Option<Pubkey>,
+    schema: Option<Pubkey>,
+) -> Result<VerificationResult> {
+    let result = verify_credential(credential_program, issuer, credential, tombstone, hash)?;
+    result.require_valid()?;
+    if let Some(subject) = subject {
+        require!(
+            result.subject == Some(subject),
+            CredentialError::SubjectMismatch
+        );
+    }
+    if let Some(schema) = schema {
+        require!(
+            result.schema == Some(schema),
+            CredentialError::SchemaMismatch
+        );
+    }
+    Ok(result)
+}
+
+/// Reads the return data left by the last `verify_credential` CPI, rejecting
+/// data set by any other program.
+pub fn read_verification_result() -> Result<VerificationResult> {
+    let (program_id, data) = get_return_data().ok_or(CredentialError::MissingVerificationResult)?;
+    require_keys_eq!(
+        program_id,
+        crate::ID,
+        CredentialError::MissingVerificationResult
+    );
+    VerificationResult::try_from_slice(&data)
+        .map_err(|_| error!(CredentialError::MissingVerificationResult))
+}
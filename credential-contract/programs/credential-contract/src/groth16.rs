@@ -0,0 +1,94 @@
+//! Groth16 proof verification over BN254 with the `alt_bn128` syscalls.
+//!
+//! Points use the EIP-197 encoding the syscalls expect: a G1 point is
+//! `x || y` and a G2 point is `x.c1 || x.c0 || y.c1 || y.c0`, every
+//! coordinate a 32-byte big-endian integer. Public inputs are big-endian
+//! scalar field elements. A proof holds for public inputs `x` when
+//!
+//! `e(-A, B) * e(alpha, beta) * e(ic[0] + sum(x[i] * ic[i + 1]), gamma) * e(C, delta) == 1`
+
+use anchor_lang::prelude::*;
+use solana_bn254::prelude::{alt_bn128_addition, alt_bn128_multiplication, alt_bn128_pairing};
+
+use crate::constants::{BN254_BASE_MODULUS, BN254_SCALAR_MODULUS};
+use crate::error::CredentialError;
+
+/// The fixed part of a Groth16 verifying key. The per-input points (`ic`)
+/// are stored alongside it so a key can be uploaded over several
+/// transactions; see `PredicateVerifier`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct VerifyingKey {
+    pub alpha_g1: [u8; 64],
+    pub beta_g2: [u8; 128],
+    pub gamma_g2: [u8; 128],
+    pub delta_g2: [u8; 128],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Groth16Proof {
+    pub a: [u8; 64],
+    pub b: [u8; 128],
+    pub c: [u8; 64],
+}
+
+/// Checks `proof` for `public_inputs` against `key` and its input points
+/// `ic`, which must number one more than the inputs.
+pub fn verify(
+    key: &VerifyingKey,
+    ic: &[[u8; 64]],
+    proof: &Groth16Proof,
+    public_inputs: &[[u8; 32]],
+) -> Result<()> {
+    require!(
+        ic.len() == public_inputs.len() + 1,
+        CredentialError::PredicateInputMismatch
+    );
+
+    let mut prepared = ic[0].to_vec();
+    for (input, point) in public_inputs.iter().zip(&ic[1..]) {
+        require!(
+            input[..] < BN254_SCALAR_MODULUS[..],
+            CredentialError::InvalidFieldElement
+        );
+        let term = alt_bn128_multiplication(&[point.as_slice(), input].concat())
+            .map_err(|_| CredentialError::InvalidVerifyingKey)?;
+        prepared = alt_bn128_addition(&[prepared, term].concat())
+            .map_err(|_| CredentialError::InvalidVerifyingKey)?;
+    }
+
+    let pairing_input = [
+        negate_g1(&proof.a).as_slice(),
+        &proof.b,
+        &key.alpha_g1,
+        &key.beta_g2,
+        &prepared,
+        &key.gamma_g2,
+        &proof.c,
+        &key.delta_g2,
+    ]
+    .concat();
+    let result = alt_bn128_pairing(&pairing_input).map_err(|_| CredentialError::InvalidZkProof)?;
+    require!(
+        result.last() == Some(&1) && result[..result.len() - 1].iter().all(|b| *b == 0),
+        CredentialError::InvalidZkProof
+    );
+    Ok(())
+}
+
+/// `-point`: the same `x` with `y` replaced by `q - y`. The point at
+/// infinity, encoded as all zeroes, is its own negation.
+fn negate_g1(point: &[u8; 64]) -> [u8; 64] {
+    let mut negated = *point;
+    if point[32..].iter().all(|b| *b == 0) {
+        return negated;
+    }
+    let mut borrow = 0u16;
+    for i in (0..32).rev() {
+        let diff = u16::from(BN254_BASE_MODULUS[i])
+            .wrapping_sub(u16::from(point[32 + i]))
+            .wrapping_sub(borrow);
+        negated[32 + i] = diff as u8;
+        borrow = (diff >> 8) & 1;
+    }
+    negated
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::CONFIG_SEED;
+use crate::error::CredentialError;
+use crate::events::AdminTransferred;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.pending_admin == Some(pending_admin.key())
+            @ CredentialError::NotPendingAdmin,
+    )]
+    pub config: Account<'info, Config>,
+    pub pending_admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let previous_admin = config.admin;
+    config.admin = ctx.accounts.pending_admin.key();
+    config.pending_admin = None;
+
+    emit!(AdminTransferred {
+        previous_admin,
+        admin: config.admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, DELEGATE_SEED};
+use crate::error::CredentialError;
+use crate::events::DelegateAdded;
+use crate::state::{Config, DelegateScope, Issuer, IssuerDelegate, IssuerStatus};
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct AddIssuerDelegate<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        has_one = authority @ CredentialError::UnregisteredIssuer,
+        constraint = issuer.status == IssuerStatus::Active @ CredentialError::IssuerNotActive,
+    )]
+    pub issuer: Account<'info, Issuer>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + IssuerDelegate::INIT_SPACE,
+        seeds = [DELEGATE_SEED, issuer.key().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub issuer_delegate: Account<'info, IssuerDelegate>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<AddIssuerDelegate>,
+    delegate: Pubkey,
+    scope: DelegateScope,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let issuer_delegate = &mut ctx.accounts.issuer_delegate;
+    issuer_delegate.set_inner(IssuerDelegate::new(
+        ctx.accounts.issuer.key(),
+        delegate,
+        scope,
+        &clock,
+        ctx.bumps.issuer_delegate,
+    )?);
+
+    emit!(DelegateAdded {
+        issuer: issuer_delegate.issuer,
+        delegate,
+        scope,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
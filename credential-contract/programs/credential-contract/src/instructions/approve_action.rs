@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, MULTISIG_SEED, PROPOSAL_SEED};
+use crate::error::CredentialError;
+use crate::events::ActionApproved;
+use crate::state::{Config, IssuerMultisig, Proposal};
+
+#[derive(Accounts)]
+pub struct ApproveAction<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [MULTISIG_SEED, multisig.issuer.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, IssuerMultisig>,
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = multisig,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    pub approver: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ApproveAction>) -> Result<()> {
+    let approver = ctx.accounts.approver.key();
+    let multisig = &ctx.accounts.multisig;
+    multisig.require_signer(&approver)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        !proposal.approvals.contains(&approver),
+        CredentialError::AlreadyApproved
+    );
+    // Approvals by signers removed since the proposal was made have lapsed;
+    // dropping them keeps the list within the current signer set.
+    proposal
+        .approvals
+        .retain(|signer| multisig.signers.contains(signer));
+    proposal.approvals.push(approver);
+
+    emit!(ActionApproved {
+        proposal: proposal.key(),
+        approver,
+        approvals: multisig.approvals_of(proposal) as u8,
+        threshold: multisig.threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
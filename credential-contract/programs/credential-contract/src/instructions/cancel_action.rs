@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, MULTISIG_SEED, PROPOSAL_SEED};
+use crate::error::CredentialError;
+use crate::events::ActionCancelled;
+use crate::state::{Config, IssuerMultisig, Proposal};
+
+#[derive(Accounts)]
+pub struct CancelAction<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [MULTISIG_SEED, multisig.issuer.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, IssuerMultisig>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = multisig,
+        has_one = proposer @ CredentialError::NotProposer,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    /// The proposer withdraws the proposal and recovers its rent, even after
+    /// leaving the signer set.
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelAction>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    emit!(ActionCancelled {
+        multisig: proposal.multisig,
+        proposal: proposal.key(),
+        index: proposal.index,
+        cancelled_by: ctx.accounts.proposer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
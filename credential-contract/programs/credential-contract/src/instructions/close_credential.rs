@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, CREDENTIAL_SEED, TOMBSTONE_SEED};
+use crate::error::CredentialError;
+use crate::events::CredentialClosed;
+use crate::state::{Config, Credential, Issuer, Tombstone};
+
+#[derive(Accounts)]
+pub struct CloseCredential<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [CREDENTIAL_SEED, credential.issuer.as_ref(), credential.hash.as_ref()],
+        bump = credential.bump,
+        has_one = issuer @ CredentialError::IssuerMismatch,
+    )]
+    pub credential: Account<'info, Credential>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Tombstone::INIT_SPACE,
+        seeds = [TOMBSTONE_SEED, issuer.key().as_ref(), credential.hash.as_ref()],
+        bump
+    )]
+    pub tombstone: Account<'info, Tombstone>,
+    #[account(has_one = authority @ CredentialError::UnregisteredIssuer)]
+    pub issuer: Account<'info, Issuer>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: only receives the credential account's lamports.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Deletes a credential issued in error or no longer needed, refunding its
+/// rent to `recipient`. The much smaller tombstone keeps the hash reserved
+/// and records how the credential ended; see `Credential::tombstone`.
+pub fn handler(ctx: Context<CloseCredential>) -> Result<()> {
+    let credential = &ctx.accounts.credential;
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.tombstone.set_inner(credential.tombstone(
+        ctx.accounts.authority.key(),
+        now,
+        ctx.bumps.tombstone,
+    )?);
+
+    emit!(CredentialClosed {
+        credential: credential.key(),
+        issuer: credential.issuer,
+        hash: credential.hash,
+        recipient: ctx.accounts.recipient.key(),
+        closed_at: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, MULTISIG_SEED};
+use crate::error::CredentialError;
+use crate::events::{IssuerAuthorityRotated, IssuerMultisigCreated};
+use crate::state::{Config, Issuer, IssuerMultisig};
+
+#[derive(Accounts)]
+pub struct CreateIssuerMultisig<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut, has_one = authority @ CredentialError::UnregisteredIssuer)]
+    pub issuer: Account<'info, Issuer>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + IssuerMultisig::INIT_SPACE,
+        seeds = [MULTISIG_SEED, issuer.key().as_ref()],
+        bump
+    )]
+    pub multisig: Account<'info, IssuerMultisig>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Hands the issuer over to an M-of-N signer set. The multisig account
+/// becomes the issuer authority, so the current key retires into
+/// `authority_history` exactly as with `rotate_issuer_authority`, and
+/// delegates that key appointed stop working.
+pub fn handler(
+    ctx: Context<CreateIssuerMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    IssuerMultisig::validate_config(&signers, threshold)?;
+
+    let clock = Clock::get()?;
+    let issuer = &mut ctx.accounts.issuer;
+    let multisig = &mut ctx.accounts.multisig;
+    let previous_authority = issuer.set_multisig(multisig.key(), clock.slot)?;
+
+    multisig.set_inner(IssuerMultisig {
+        issuer: issuer.key(),
+        signers: signers.clone(),
+        threshold,
+        proposal_count: 0,
+        created_at: clock.unix_timestamp,
+        bump: ctx.bumps.multisig,
+    });
+
+    emit!(IssuerAuthorityRotated {
+        issuer: issuer.key(),
+        previous_authority,
+        authority: multisig.key(),
+        rotated_by: previous_authority,
+        effective_from_slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    });
+    emit!(IssuerMultisigCreated {
+        issuer: issuer.key(),
+        multisig: multisig.key(),
+        signers,
+        threshold,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,675 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+
+use crate::attestation::require_attestation;
+use crate::constants::{
+    BATCH_SEED, CONFIG_SEED, CREDENTIAL_SEED, DELEGATE_SEED, MULTISIG_SEED, PROPOSAL_SEED,
+    REVOCATION_LIST_SEED, SCHEMA_SEED, TOMBSTONE_SEED,
+};
+use crate::error::CredentialError;
+use crate::events::{
+    ActionExecuted, BatchCredentialsRevoked, CredentialBatchIssued, CredentialClosed,
+    CredentialRenewed, CredentialRevoked, CredentialSuperseded, DelegateAdded, DelegateRemoved,
+    IssuerSigningKeyChanged, MultisigSignersChanged, SchemaRegistered,
+};
+use crate::instructions::set_credential_suspended::emit_suspension;
+use crate::instructions::store_credential::emit_issued;
+use crate::state::{
+    Config, Credential, CredentialBatch, CredentialSchema, CredentialTerms, Issuer, IssuerDelegate,
+    IssuerMultisig, IssuerStatus, MultisigAction, Proposal, RevocationList, Tombstone,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteAction<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        constraint = issuer.authority == multisig.key() @ CredentialError::UnregisteredIssuer,
+    )]
+    pub issuer: Account<'info, Issuer>,
+    #[account(
+        mut,
+        seeds = [MULTISIG_SEED, issuer.key().as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, IssuerMultisig>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = multisig,
+        has_one = proposer,
+        constraint = multisig.approvals_of(&proposal) >= usize::from(multisig.threshold)
+            @ CredentialError::InsufficientApprovals,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: receives the proposal's rent; pinned by `has_one` above.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    /// CHECK: the account the action operates on: the credential, batch,
+    /// schema, delegate or revocation list PDA, the issuer for `SetSigningKey`
+    /// or the multisig for `ChangeSigners`. Its address is re-derived from the
+    /// action and checked in the handler.
+    #[account(mut)]
+    pub target: UncheckedAccount<'info>,
+    /// CHECK: the second account of two-account actions: the superseded
+    /// credential for `ReissueCredential`, the revocation list for
+    /// `StoreCredentialBatch` and the batch for `SetRevocationBits`.
+    /// Re-derived and checked like `target`.
+    #[account(mut)]
+    pub related: Option<UncheckedAccount<'info>>,
+    /// CHECK: required for `IssueCredential` and `ReissueCredential`, checked
+    /// like the tombstone in `store_credential`, and for `CloseCredential`,
+    /// which creates it.
+    #[account(mut)]
+    pub tombstone: Option<UncheckedAccount<'info>>,
+    /// Required for `IssueCredential` and `ReissueCredential`: the schema the
+    /// action names.
+    pub schema: Option<Account<'info, CredentialSchema>>,
+    /// CHECK: the instructions sysvar; required when the issuer has a signing key.
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+    /// Any key may execute an approved action; it pays for accounts the action
+    /// creates and receives the rent of accounts it closes.
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Performs an approved proposal's action with the multisig as the acting
+/// authority, then closes the proposal.
+pub fn handler(ctx: Context<ExecuteAction>) -> Result<()> {
+    let clock = Clock::get()?;
+    let accounts = ctx.accounts;
+    let issuer = &accounts.issuer;
+    let multisig = accounts.multisig.key();
+    let target = accounts.target.to_account_info();
+    let action = accounts.proposal.action.clone();
+
+    match action.clone() {
+        MultisigAction::IssueCredential {
+            schema,
+            hash,
+            algorithm,
+            subject,
+            valid_from,
+            valid_until,
+            metadata,
+        } => {
+            let terms = CredentialTerms {
+                schema,
+                hash,
+                algorithm,
+                subject,
+                valid_from,
+                valid_until,
+                metadata,
+            };
+            let credential = issue(accounts, &terms, None, &clock)?;
+            emit_issued(target.key(), &credential);
+        }
+        MultisigAction::StoreCredentialBatch {
+            root,
+            leaf_count,
+            algorithm,
+        } => {
+            require!(
+                issuer.status == IssuerStatus::Active,
+                CredentialError::IssuerNotActive
+            );
+            require!(
+                issuer.signing_key.is_none(),
+                CredentialError::SigningKeyPreventsBatch
+            );
+            let batch_bump = require_target(&target, &[BATCH_SEED, issuer.key().as_ref(), &root])?;
+            let batch = CredentialBatch::new(
+                issuer.key(),
+                multisig,
+                root,
+                leaf_count,
+                algorithm,
+                &clock,
+                batch_bump,
+            )?;
+            let list_account = related(accounts)?;
+            let list_bump = require_target(
+                &list_account,
+                &[REVOCATION_LIST_SEED, target.key().as_ref()],
+            )?;
+            let list = RevocationList::new(
+                target.key(),
+                issuer.key(),
+                leaf_count,
+                clock.unix_timestamp,
+                list_bump,
+            );
+
+            create_program_account(
+                &target,
+                &accounts.executor,
+                &accounts.system_program,
+                8 + CredentialBatch::INIT_SPACE,
+                &[BATCH_SEED, issuer.key().as_ref(), &root, &[batch_bump]],
+            )?;
+            store(&target, &batch)?;
+            create_program_account(
+                &list_account,
+                &accounts.executor,
+                &accounts.system_program,
+                RevocationList::space(leaf_count),
+                &[REVOCATION_LIST_SEED, target.key().as_ref(), &[list_bump]],
+            )?;
+            store(&list_account, &list)?;
+
+            emit!(CredentialBatchIssued {
+                batch: target.key(),
+                issuer: batch.issuer,
+                issued_by: multisig,
+                root,
+                leaf_count,
+                algorithm,
+                issued_at: clock.unix_timestamp,
+            });
+        }
+        MultisigAction::RevokeCredential { hash, reason } => {
+            let mut credential = load_credential(&target, issuer, &hash)?;
+            credential.revoke(multisig, reason, clock.unix_timestamp)?;
+            store(&target, &credential)?;
+
+            emit!(CredentialRevoked {
+                credential: target.key(),
+                issuer: credential.issuer,
+                hash,
+                reason,
+                revoked_by: multisig,
+                revoked_at: clock.unix_timestamp,
+            });
+        }
+        MultisigAction::ReissueCredential {
+            previous,
+            schema,
+            hash,
+            algorithm,
+            subject,
+            valid_from,
+            valid_until,
+            metadata,
+        } => {
+            let previous_account = related(accounts)?;
+            let mut superseded = load_credential(&previous_account, issuer, &previous)?;
+            let terms = CredentialTerms {
+                schema,
+                hash,
+                algorithm,
+                subject,
+                valid_from,
+                valid_until,
+                metadata,
+            };
+            let credential = issue(accounts, &terms, Some(previous_account.key()), &clock)?;
+            superseded.supersede(target.key())?;
+            store(&previous_account, &superseded)?;
+
+            emit_issued(target.key(), &credential);
+            emit!(CredentialSuperseded {
+                credential: previous_account.key(),
+                issuer: superseded.issuer,
+                hash: previous,
+                superseded_by: target.key(),
+                superseded_at: clock.unix_timestamp,
+            });
+        }
+        MultisigAction::RenewCredential { hash, valid_until } => {
+            require!(
+                issuer.status == IssuerStatus::Active,
+                CredentialError::IssuerNotActive
+            );
+            let mut credential = load_credential(&target, issuer, &hash)?;
+            let previous_valid_until = credential.renew(valid_until, clock.unix_timestamp)?;
+            store(&target, &credential)?;
+
+            emit!(CredentialRenewed {
+                credential: target.key(),
+                issuer: credential.issuer,
+                hash,
+                previous_valid_until,
+                valid_until,
+                renewal_count: credential.renewal_count,
+                renewed_at: clock.unix_timestamp,
+            });
+        }
+        MultisigAction::SetCredentialSuspended { hash, suspended } => {
+            let mut credential = load_credential(&target, issuer, &hash)?;
+            credential.set_suspended(suspended)?;
+            store(&target, &credential)?;
+            emit_suspension(target.key(), &credential, clock.unix_timestamp);
+        }
+        MultisigAction::SetRevocationBits { root, indices } => {
+            let batch_account = related(accounts)?;
+            require_target(
+                &batch_account,
+                &[BATCH_SEED, issuer.key().as_ref(), root.as_ref()],
+            )?;
+            let batch: CredentialBatch = load(&batch_account)?;
+            require_target(
+                &target,
+                &[REVOCATION_LIST_SEED, batch_account.key().as_ref()],
+            )?;
+            let mut list: RevocationList = load(&target)?;
+            list.revoke_all(&indices, batch.leaf_count, clock.unix_timestamp)?;
+            store(&target, &list)?;
+
+            emit!(BatchCredentialsRevoked {
+                batch: list.batch,
+                issuer: list.issuer,
+                indices,
+                revoked_count: list.revoked_count,
+                revoked_at: clock.unix_timestamp,
+            });
+        }
+        MultisigAction::CloseCredential { hash } => {
+            let credential = load_credential(&target, issuer, &hash)?;
+            let tombstone_account = accounts
+                .tombstone
+                .as_ref()
+                .ok_or(CredentialError::ActionAccountMismatch)?
+                .to_account_info();
+            let bump = require_target(
+                &tombstone_account,
+                &[TOMBSTONE_SEED, issuer.key().as_ref(), hash.as_ref()],
+            )?;
+            let tombstone = credential.tombstone(multisig, clock.unix_timestamp, bump)?;
+            create_program_account(
+                &tombstone_account,
+                &accounts.executor,
+                &accounts.system_program,
+                8 + Tombstone::INIT_SPACE,
+                &[
+                    TOMBSTONE_SEED,
+                    issuer.key().as_ref(),
+                    hash.as_ref(),
+                    &[bump],
+                ],
+            )?;
+            store(&tombstone_account, &tombstone)?;
+            close_program_account(&target, &accounts.executor)?;
+
+            emit!(CredentialClosed {
+                credential: target.key(),
+                issuer: credential.issuer,
+                hash,
+                recipient: accounts.executor.key(),
+                closed_at: clock.unix_timestamp,
+            });
+        }
+        MultisigAction::RegisterSchema {
+            schema_id,
+            version,
+            name,
+            content_hash,
+            uri,
+        } => {
+            require!(
+                issuer.status == IssuerStatus::Active,
+                CredentialError::IssuerNotActive
+            );
+            let version_seed = version.to_le_bytes();
+            let bump = require_target(
+                &target,
+                &[
+                    SCHEMA_SEED,
+                    issuer.key().as_ref(),
+                    schema_id.as_bytes(),
+                    &version_seed,
+                ],
+            )?;
+            let schema = CredentialSchema {
+                registry: issuer.key(),
+                schema_id,
+                version,
+                name,
+                content_hash,
+                uri,
+                registered_by: multisig,
+                created_at: clock.unix_timestamp,
+                bump,
+            };
+            schema.validate()?;
+            create_program_account(
+                &target,
+                &accounts.executor,
+                &accounts.system_program,
+                8 + CredentialSchema::INIT_SPACE,
+                &[
+                    SCHEMA_SEED,
+                    issuer.key().as_ref(),
+                    schema.schema_id.as_bytes(),
+                    &version_seed,
+                    &[bump],
+                ],
+            )?;
+            store(&target, &schema)?;
+
+            emit!(SchemaRegistered {
+                schema: target.key(),
+                registry: schema.registry,
+                schema_id: schema.schema_id,
+                version,
+                content_hash,
+                uri: schema.uri,
+                registered_at: clock.unix_timestamp,
+            });
+        }
+        MultisigAction::SetSigningKey { signing_key } => {
+            require_keys_eq!(
+                target.key(),
+                issuer.key(),
+                CredentialError::ActionAccountMismatch
+            );
+            let issuer = &mut accounts.issuer;
+            let previous_signing_key = issuer.signing_key;
+            issuer.signing_key = signing_key;
+
+            emit!(IssuerSigningKeyChanged {
+                issuer: issuer.key(),
+                previous_signing_key,
+                signing_key,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        MultisigAction::AddDelegate { delegate, scope } => {
+            require!(
+                issuer.status == IssuerStatus::Active,
+                CredentialError::IssuerNotActive
+            );
+            let bump = require_target(
+                &target,
+                &[DELEGATE_SEED, issuer.key().as_ref(), delegate.as_ref()],
+            )?;
+            let issuer_delegate = IssuerDelegate::new(issuer.key(), delegate, scope, &clock, bump)?;
+            create_program_account(
+                &target,
+                &accounts.executor,
+                &accounts.system_program,
+                8 + IssuerDelegate::INIT_SPACE,
+                &[
+                    DELEGATE_SEED,
+                    issuer.key().as_ref(),
+                    delegate.as_ref(),
+                    &[bump],
+                ],
+            )?;
+            store(&target, &issuer_delegate)?;
+
+            emit!(DelegateAdded {
+                issuer: issuer.key(),
+                delegate,
+                scope,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        MultisigAction::RemoveDelegate { delegate } => {
+            require_target(
+                &target,
+                &[DELEGATE_SEED, issuer.key().as_ref(), delegate.as_ref()],
+            )?;
+            let issuer_delegate: IssuerDelegate = load(&target)?;
+            close_program_account(&target, &accounts.executor)?;
+
+            emit!(DelegateRemoved {
+                issuer: issuer_delegate.issuer,
+                delegate,
+                issued_count: issuer_delegate.issued_count,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        MultisigAction::ChangeSigners { signers, threshold } => {
+            IssuerMultisig::validate_config(&signers, threshold)?;
+            require_keys_eq!(
+                target.key(),
+                multisig,
+                CredentialError::ActionAccountMismatch
+            );
+            let multisig_account = &mut accounts.multisig;
+            multisig_account.signers = signers.clone();
+            multisig_account.threshold = threshold;
+
+            emit!(MultisigSignersChanged {
+                issuer: multisig_account.issuer,
+                multisig,
+                signers,
+                threshold,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
+
+    let proposal = &accounts.proposal;
+    emit!(ActionExecuted {
+        multisig,
+        proposal: proposal.key(),
+        index: proposal.index,
+        action,
+        executed_by: accounts.executor.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Creates the credential for `terms` at `target` the way `store_credential`
+/// and `reissue_credential` do, with the multisig as issuer.
+fn issue(
+    accounts: &ExecuteAction,
+    terms: &CredentialTerms,
+    supersedes: Option<Pubkey>,
+    clock: &Clock,
+) -> Result<Credential> {
+    let issuer = &accounts.issuer;
+    let target = accounts.target.to_account_info();
+    require!(
+        issuer.status == IssuerStatus::Active,
+        CredentialError::IssuerNotActive
+    );
+    let tombstone = accounts
+        .tombstone
+        .as_ref()
+        .ok_or(CredentialError::ActionAccountMismatch)?;
+    require_target(
+        tombstone,
+        &[TOMBSTONE_SEED, issuer.key().as_ref(), terms.hash.as_ref()],
+    )?;
+    require!(
+        tombstone.data_is_empty(),
+        CredentialError::DuplicateCredential
+    );
+    require!(target.data_is_empty(), CredentialError::DuplicateCredential);
+    let schema = accounts
+        .schema
+        .as_ref()
+        .ok_or(CredentialError::ActionAccountMismatch)?;
+    require_keys_eq!(
+        schema.key(),
+        terms.schema,
+        CredentialError::ActionAccountMismatch
+    );
+    require!(
+        schema.is_available_to(&issuer.key(), &accounts.config.key()),
+        CredentialError::SchemaNotAvailable
+    );
+
+    terms.validate(clock.unix_timestamp)?;
+    let attestation = require_attestation(
+        issuer,
+        accounts.instructions.as_deref(),
+        terms,
+        supersedes.as_ref(),
+    )?;
+
+    let bump = require_target(
+        &target,
+        &[CREDENTIAL_SEED, issuer.key().as_ref(), terms.hash.as_ref()],
+    )?;
+    create_program_account(
+        &target,
+        &accounts.executor,
+        &accounts.system_program,
+        8 + Credential::INIT_SPACE,
+        &[
+            CREDENTIAL_SEED,
+            issuer.key().as_ref(),
+            terms.hash.as_ref(),
+            &[bump],
+        ],
+    )?;
+    let credential = Credential {
+        attestation,
+        ..Credential::new(
+            issuer.key(),
+            accounts.multisig.key(),
+            terms,
+            supersedes,
+            clock,
+            bump,
+        )
+    };
+    store(&target, &credential)?;
+    Ok(credential)
+}
+
+/// The `related` account, required by actions that touch two accounts.
+fn related<'info>(accounts: &ExecuteAction<'info>) -> Result<AccountInfo<'info>> {
+    accounts
+        .related
+        .as_ref()
+        .map(|account| account.to_account_info())
+        .ok_or_else(|| error!(CredentialError::ActionAccountMismatch))
+}
+
+/// Loads the issuer's credential for `hash` from `target`.
+fn load_credential(
+    target: &AccountInfo,
+    issuer: &Account<Issuer>,
+    hash: &[u8; 32],
+) -> Result<Credential> {
+    require_target(
+        target,
+        &[CREDENTIAL_SEED, issuer.key().as_ref(), hash.as_ref()],
+    )?;
+    require!(
+        target.owner == &crate::ID && !target.data_is_empty(),
+        CredentialError::CredentialNotFound
+    );
+    Credential::try_deserialize(&mut &target.try_borrow_data()?[..])
+}
+
+/// Deserializes a program account whose address has already been checked.
+fn load<T: AccountDeserialize>(target: &AccountInfo) -> Result<T> {
+    require!(
+        target.owner == &crate::ID,
+        CredentialError::ActionAccountMismatch
+    );
+    T::try_deserialize(&mut &target.try_borrow_data()?[..])
+}
+
+fn store<T: AccountSerialize>(target: &AccountInfo, value: &T) -> Result<()> {
+    value.try_serialize(&mut &mut target.try_borrow_mut_data()?[..])
+}
+
+/// Checks that `target` is the PDA for `seeds` and returns its bump.
+fn require_target(target: &AccountInfo, seeds: &[&[u8]]) -> Result<u8> {
+    let (address, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(
+        target.key(),
+        address,
+        CredentialError::ActionAccountMismatch
+    );
+    Ok(bump)
+}
+
+/// Allocates `space` bytes at the PDA `target` owned by this program, the way
+/// Anchor's `init` does, including when someone has pre-funded the address.
+fn create_program_account<'info>(
+    target: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system: &Program<'info, System>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    require!(
+        target.owner == &system_program::ID && target.data_is_empty(),
+        CredentialError::ActionAccountInUse
+    );
+    let rent = Rent::get()?.minimum_balance(space);
+    let signer = &[signer_seeds];
+    let system = system.to_account_info();
+
+    if target.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system,
+                CreateAccount {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+                signer,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let shortfall = rent.saturating_sub(target.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system.clone(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system.clone(),
+            Allocate {
+                account_to_allocate: target.clone(),
+            },
+            signer,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system,
+            Assign {
+                account_to_assign: target.clone(),
+            },
+            signer,
+        ),
+        &crate::ID,
+    )
+}
+
+/// Closes a program-owned account the way Anchor's `close` constraint does.
+fn close_program_account<'info>(
+    target: &AccountInfo<'info>,
+    destination: &Signer<'info>,
+) -> Result<()> {
+    let destination = destination.to_account_info();
+    **destination.try_borrow_mut_lamports()? += target.lamports();
+    **target.try_borrow_mut_lamports()? = 0;
+    target.assign(&system_program::ID);
+    target.resize(0)?;
+    Ok(())
+}
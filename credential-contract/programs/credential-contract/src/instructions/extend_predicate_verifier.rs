@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, PREDICATE_SEED};
+use crate::error::CredentialError;
+use crate::events::PredicateVerifierExtended;
+use crate::state::{Config, PredicateVerifier};
+
+#[derive(Accounts)]
+pub struct ExtendPredicateVerifier<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ CredentialError::NotAdmin,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [PREDICATE_SEED, verifier.predicate_id.as_bytes()],
+        bump = verifier.bump,
+    )]
+    pub verifier: Account<'info, PredicateVerifier>,
+    pub admin: Signer<'info>,
+}
+
+/// Appends verifying key input points in order. Once all `input_count + 1`
+/// are present the key is complete and can no longer change.
+pub fn handler(ctx: Context<ExtendPredicateVerifier>, ic: Vec<[u8; 64]>) -> Result<()> {
+    let verifier = &mut ctx.accounts.verifier;
+    require!(
+        !ic.is_empty() && verifier.ic.len() + ic.len() <= usize::from(verifier.input_count) + 1,
+        CredentialError::InvalidVerifyingKey
+    );
+    verifier.ic.extend(ic);
+
+    emit!(PredicateVerifierExtended {
+        verifier: verifier.key(),
+        ic_len: verifier.ic.len() as u8,
+        complete: verifier.is_complete(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, CONFIG_VERSION};
+use crate::error::CredentialError;
+use crate::events::ConfigInitialized;
+use crate::program::CredentialContract;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Config::INIT_SPACE,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, CredentialContract>,
+    /// Only the upgrade authority may claim the admin role, so the first
+    /// caller after deployment cannot front-run the deployer.
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ CredentialError::NotUpgradeAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<Initialize>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.pending_admin = None;
+    config.paused = false;
+    config.version = CONFIG_VERSION;
+    config.bump = ctx.bumps.config;
+
+    emit!(ConfigInitialized {
+        admin: config.admin,
+        version: config.version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
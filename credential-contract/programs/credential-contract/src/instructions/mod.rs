@@ -0,0 +1,69 @@
+// Every instruction module exposes its own `handler`; callers always reach it
+// through the module path, so the clashing glob re-exports are harmless.
+#![allow(ambiguous_glob_reexports)]
+
+pub mod accept_admin;
+pub mod add_issuer_delegate;
+pub mod approve_action;
+pub mod cancel_action;
+pub mod close_credential;
+pub mod create_issuer_multisig;
+pub mod execute_action;
+pub mod extend_predicate_verifier;
+pub mod initialize;
+pub mod nominate_admin;
+pub mod propose_action;
+pub mod register_issuer;
+pub mod register_predicate_verifier;
+pub mod register_schema;
+pub mod reissue_credential;
+pub mod remove_issuer_delegate;
+pub mod renew_credential;
+pub mod revoke_credential;
+pub mod rotate_issuer_authority;
+pub mod set_credential_suspended;
+pub mod set_issuer_eth_address;
+pub mod set_issuer_signing_key;
+pub mod set_issuer_status;
+pub mod set_paused;
+pub mod set_revocation_bits;
+pub mod store_credential;
+pub mod store_credential_batch;
+pub mod verify_claim;
+pub mod verify_commitment_opening;
+pub mod verify_credential;
+pub mod verify_in_batch;
+pub mod verify_zk_predicate;
+
+pub use accept_admin::*;
+pub use add_issuer_delegate::*;
+pub use approve_action::*;
+pub use cancel_action::*;
+pub use close_credential::*;
+pub use create_issuer_multisig::*;
+pub use execute_action::*;
+pub use extend_predicate_verifier::*;
+pub use initialize::*;
+pub use nominate_admin::*;
+pub use propose_action::*;
+pub use register_issuer::*;
+pub use register_predicate_verifier::*;
+pub use register_schema::*;
+pub use reissue_credential::*;
+pub use remove_issuer_delegate::*;
+pub use renew_credential::*;
+pub use revoke_credential::*;
+pub use rotate_issuer_authority::*;
+pub use set_credential_suspended::*;
+pub use set_issuer_eth_address::*;
+pub use set_issuer_signing_key::*;
+pub use set_issuer_status::*;
+pub use set_paused::*;
+pub use set_revocation_bits::*;
+pub use store_credential::*;
+pub use store_credential_batch::*;
+pub use verify_claim::*;
+pub use verify_commitment_opening::*;
+pub use verify_credential::*;
+pub use verify_in_batch::*;
+pub use verify_zk_predicate::*;
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::CONFIG_SEED;
+use crate::error::CredentialError;
+use crate::events::AdminNominated;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct NominateAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ CredentialError::NotAdmin,
+    )]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+}
+
+/// First step of an admin transfer. Passing `None` cancels a pending nomination.
+pub fn handler(ctx: Context<NominateAdmin>, new_admin: Option<Pubkey>) -> Result<()> {
+    ctx.accounts.config.pending_admin = new_admin;
+
+    emit!(AdminNominated {
+        admin: ctx.accounts.admin.key(),
+        pending_admin: new_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, MULTISIG_SEED, PROPOSAL_SEED};
+use crate::error::CredentialError;
+use crate::events::ActionProposed;
+use crate::state::{Config, Issuer, IssuerMultisig, MultisigAction, Proposal};
+
+#[derive(Accounts)]
+pub struct ProposeAction<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        constraint = issuer.authority == multisig.key() @ CredentialError::UnregisteredIssuer,
+    )]
+    pub issuer: Account<'info, Issuer>,
+    #[account(
+        mut,
+        seeds = [MULTISIG_SEED, issuer.key().as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, IssuerMultisig>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &multisig.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Records `action` for approval. Proposing counts as the proposer's approval.
+pub fn handler(ctx: Context<ProposeAction>, action: MultisigAction) -> Result<()> {
+    let proposer = ctx.accounts.proposer.key();
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.require_signer(&proposer)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let index = multisig.proposal_count;
+    multisig.proposal_count += 1;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.set_inner(Proposal {
+        multisig: multisig.key(),
+        index,
+        proposer,
+        action: action.clone(),
+        approvals: vec![proposer],
+        created_at: now,
+        bump: ctx.bumps.proposal,
+    });
+
+    emit!(ActionProposed {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        index,
+        proposer,
+        action,
+        timestamp: now,
+    });
+    Ok(())
+}
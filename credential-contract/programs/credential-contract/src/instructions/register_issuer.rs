@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    CONFIG_SEED, ISSUER_SEED, MAX_ISSUER_DID_LEN, MAX_ISSUER_NAME_LEN, MAX_ISSUER_WEBSITE_LEN,
+};
+use crate::error::CredentialError;
+use crate::events::IssuerRegistered;
+use crate::state::{Config, Issuer, IssuerStatus};
+
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct RegisterIssuer<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ CredentialError::NotAdmin,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Issuer::INIT_SPACE,
+        seeds = [ISSUER_SEED, authority.as_ref()],
+        bump
+    )]
+    pub issuer: Account<'info, Issuer>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterIssuer>,
+    authority: Pubkey,
+    name: String,
+    website: String,
+    did: String,
+) -> Result<()> {
+    require!(
+        name.len() <= MAX_ISSUER_NAME_LEN
+            && website.len() <= MAX_ISSUER_WEBSITE_LEN
+            && did.len() <= MAX_ISSUER_DID_LEN,
+        CredentialError::IssuerFieldTooLong
+    );
+
+    let issuer = &mut ctx.accounts.issuer;
+    issuer.authority = authority;
+    issuer.name = name;
+    issuer.website = website;
+    issuer.did = did;
+    issuer.status = IssuerStatus::Active;
+    issuer.signing_key = None;
+    issuer.eth_address = None;
+    let clock = Clock::get()?;
+    issuer.registered_at = clock.unix_timestamp;
+    issuer.authority_since_slot = clock.slot;
+    issuer.authority_history = Vec::new();
+    issuer.multisig = None;
+    issuer.multisig_since_slot = 0;
+    issuer.bump = ctx.bumps.issuer;
+
+    emit!(IssuerRegistered {
+        issuer: issuer.key(),
+        authority,
+        name: issuer.name.clone(),
+        registered_at: issuer.registered_at,
+    });
+    Ok(())
+}
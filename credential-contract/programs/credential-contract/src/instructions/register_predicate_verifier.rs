@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    BN254_SCALAR_MODULUS, CONFIG_SEED, MAX_PREDICATE_ID_LEN, MAX_PREDICATE_INPUTS, PREDICATE_SEED,
+};
+use crate::error::CredentialError;
+use crate::events::PredicateVerifierRegistered;
+use crate::groth16::VerifyingKey;
+use crate::state::{Config, CredentialSchema, PredicateVerifier};
+
+#[derive(Accounts)]
+#[instruction(predicate_id: String)]
+pub struct RegisterPredicateVerifier<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ CredentialError::NotAdmin,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    /// Schema whose Poseidon credentials the circuit opens.
+    pub schema: Account<'info, CredentialSchema>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PredicateVerifier::INIT_SPACE,
+        seeds = [PREDICATE_SEED, predicate_id.as_bytes()],
+        bump
+    )]
+    pub verifier: Account<'info, PredicateVerifier>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers a predicate circuit's verifying key without its input points,
+/// which rarely fit in the same transaction; `extend_predicate_verifier`
+/// uploads them. `parameters` are the circuit's public inputs that define the
+/// predicate and are fixed from now on.
+pub fn handler(
+    ctx: Context<RegisterPredicateVerifier>,
+    predicate_id: String,
+    credential_count: u8,
+    parameters: Vec<[u8; 32]>,
+    key: VerifyingKey,
+) -> Result<()> {
+    require!(
+        !predicate_id.is_empty() && predicate_id.len() <= MAX_PREDICATE_ID_LEN,
+        CredentialError::InvalidVerifyingKey
+    );
+    // The commitments, the parameters and the challenge.
+    let input_count = usize::from(credential_count) + parameters.len() + 1;
+    require!(
+        credential_count > 0 && input_count <= MAX_PREDICATE_INPUTS,
+        CredentialError::InvalidVerifyingKey
+    );
+    require!(
+        parameters
+            .iter()
+            .all(|parameter| parameter[..] < BN254_SCALAR_MODULUS[..]),
+        CredentialError::InvalidFieldElement
+    );
+    let input_count = input_count as u8;
+
+    let now = Clock::get()?.unix_timestamp;
+    let schema = ctx.accounts.schema.key();
+    let verifier = &mut ctx.accounts.verifier;
+    verifier.set_inner(PredicateVerifier {
+        predicate_id,
+        schema,
+        credential_count,
+        input_count,
+        parameters: parameters.clone(),
+        key,
+        ic: Vec::new(),
+        registered_by: ctx.accounts.admin.key(),
+        created_at: now,
+        bump: ctx.bumps.verifier,
+    });
+
+    emit!(PredicateVerifierRegistered {
+        verifier: verifier.key(),
+        predicate_id: verifier.predicate_id.clone(),
+        schema,
+        credential_count,
+        input_count,
+        parameters,
+        registered_at: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, SCHEMA_SEED};
+use crate::error::CredentialError;
+use crate::events::SchemaRegistered;
+use crate::state::{Config, CredentialSchema, Issuer, IssuerStatus};
+
+#[derive(Accounts)]
+#[instruction(schema_id: String, version: u16)]
+pub struct RegisterSchema<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    /// Owning issuer; omit to register a program-wide schema as the admin.
+    pub issuer: Option<Account<'info, Issuer>>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CredentialSchema::INIT_SPACE,
+        seeds = [
+            SCHEMA_SEED,
+            issuer.as_ref().map_or(config.key(), |issuer| issuer.key()).as_ref(),
+            schema_id.as_bytes(),
+            &version.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub schema: Account<'info, CredentialSchema>,
+    /// Issuer authority, or the program admin when `issuer` is omitted.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterSchema>,
+    schema_id: String,
+    version: u16,
+    name: String,
+    content_hash: [u8; 32],
+    uri: String,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let registry = match &ctx.accounts.issuer {
+        Some(issuer) => {
+            require_keys_eq!(
+                issuer.authority,
+                authority,
+                CredentialError::UnregisteredIssuer
+            );
+            require!(
+                issuer.status == IssuerStatus::Active,
+                CredentialError::IssuerNotActive
+            );
+            issuer.key()
+        }
+        None => {
+            require_keys_eq!(
+                ctx.accounts.config.admin,
+                authority,
+                CredentialError::NotAdmin
+            );
+            ctx.accounts.config.key()
+        }
+    };
+
+    let now = Clock::get()?.unix_timestamp;
+    let schema = CredentialSchema {
+        registry,
+        schema_id,
+        version,
+        name,
+        content_hash,
+        uri,
+        registered_by: authority,
+        created_at: now,
+        bump: ctx.bumps.schema,
+    };
+    schema.validate()?;
+    ctx.accounts.schema.set_inner(schema);
+    let schema = &ctx.accounts.schema;
+
+    emit!(SchemaRegistered {
+        schema: schema.key(),
+        registry,
+        schema_id: schema.schema_id.clone(),
+        version,
+        content_hash,
+        uri: schema.uri.clone(),
+        registered_at: now,
+    });
+    Ok(())
+}
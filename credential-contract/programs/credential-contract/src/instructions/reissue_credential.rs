@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+
+use crate::attestation::require_attestation;
+use crate::constants::{CONFIG_SEED, CREDENTIAL_SEED, DELEGATE_SEED, TOMBSTONE_SEED};
+use crate::error::CredentialError;
+use crate::events::CredentialSuperseded;
+use crate::instructions::store_credential::emit_issued;
+use crate::state::{
+    Config, Credential, CredentialMetadata, CredentialSchema, CredentialTerms, DelegateAction,
+    HashAlgorithm, Issuer, IssuerDelegate, IssuerStatus,
+};
+
+#[derive(Accounts)]
+#[instruction(hash: [u8; 32])]
+pub struct ReissueCredential<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [CREDENTIAL_SEED, previous.issuer.as_ref(), previous.hash.as_ref()],
+        bump = previous.bump,
+        has_one = issuer @ CredentialError::IssuerMismatch,
+    )]
+    pub previous: Account<'info, Credential>,
+    /// CHECK: only its emptiness matters, as in `store_credential`.
+    #[account(
+        seeds = [TOMBSTONE_SEED, issuer.key().as_ref(), hash.as_ref()],
+        bump,
+        constraint = tombstone.data_is_empty() @ CredentialError::DuplicateCredential,
+    )]
+    pub tombstone: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Credential::INIT_SPACE,
+        seeds = [CREDENTIAL_SEED, issuer.key().as_ref(), hash.as_ref()],
+        bump
+    )]
+    pub credential: Account<'info, Credential>,
+    #[account(
+        constraint = issuer.status == IssuerStatus::Active @ CredentialError::IssuerNotActive,
+    )]
+    pub issuer: Account<'info, Issuer>,
+    #[account(
+        constraint = schema.is_available_to(&issuer.key(), &config.key())
+            @ CredentialError::SchemaNotAvailable,
+    )]
+    pub schema: Account<'info, CredentialSchema>,
+    /// Required when `authority` is a delegate rather than the issuer's root key.
+    #[account(
+        mut,
+        seeds = [DELEGATE_SEED, issuer.key().as_ref(), authority.key().as_ref()],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Account<'info, IssuerDelegate>>,
+    /// CHECK: the instructions sysvar; required when the issuer has a signing key.
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+    /// Issuer authority or delegate; any relayer when the reissue carries a
+    /// secp256k1 attestation from the issuer's Ethereum address.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Issues a corrected credential under a new hash and links the two: the new
+/// credential's `supersedes` points back, the old one's `superseded_by` forward.
+pub fn handler(
+    ctx: Context<ReissueCredential>,
+    hash: [u8; 32],
+    algorithm: HashAlgorithm,
+    subject: Option<Pubkey>,
+    valid_from: Option<i64>,
+    valid_until: Option<i64>,
+    metadata: Option<CredentialMetadata>,
+) -> Result<()> {
+    let previous = &mut ctx.accounts.previous;
+    let clock = Clock::get()?;
+    let authority = ctx.accounts.authority.key();
+    let terms = CredentialTerms {
+        schema: ctx.accounts.schema.key(),
+        hash,
+        algorithm,
+        subject,
+        valid_from,
+        valid_until,
+        metadata,
+    };
+    terms.validate(clock.unix_timestamp)?;
+    let attestation = require_attestation(
+        &ctx.accounts.issuer,
+        ctx.accounts.instructions.as_deref(),
+        &terms,
+        Some(&previous.key()),
+    )?;
+    ctx.accounts.issuer.authorize_issuance(
+        &authority,
+        ctx.accounts.delegate.as_deref(),
+        attestation.as_ref(),
+        terms.schema,
+        clock.unix_timestamp,
+    )?;
+    if let Some(delegate) = ctx.accounts.delegate.as_mut() {
+        // Superseding retires the previous credential, so a delegate must also
+        // be able to issue its schema and to revoke.
+        delegate.authorize(
+            DelegateAction::Issue {
+                schema: previous.schema,
+            },
+            clock.unix_timestamp,
+        )?;
+        delegate.authorize(DelegateAction::Revoke, clock.unix_timestamp)?;
+        delegate.issued_count += 1;
+    }
+
+    let credential = &mut ctx.accounts.credential;
+    credential.set_inner(Credential {
+        attestation,
+        ..Credential::new(
+            ctx.accounts.issuer.key(),
+            authority,
+            &terms,
+            Some(previous.key()),
+            &clock,
+            ctx.bumps.credential,
+        )
+    });
+
+    previous.supersede(credential.key())?;
+
+    emit_issued(credential.key(), credential);
+    emit!(CredentialSuperseded {
+        credential: previous.key(),
+        issuer: previous.issuer,
+        hash: previous.hash,
+        superseded_by: credential.key(),
+        superseded_at: clock.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, DELEGATE_SEED};
+use crate::error::CredentialError;
+use crate::events::DelegateRemoved;
+use crate::state::{Config, Issuer, IssuerDelegate};
+
+#[derive(Accounts)]
+pub struct RemoveIssuerDelegate<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(has_one = authority @ CredentialError::UnregisteredIssuer)]
+    pub issuer: Account<'info, Issuer>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [DELEGATE_SEED, issuer.key().as_ref(), issuer_delegate.delegate.as_ref()],
+        bump = issuer_delegate.bump,
+    )]
+    pub issuer_delegate: Account<'info, IssuerDelegate>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Withdraws a delegate's authority. Credentials it already issued keep
+/// their `issued_by` and stay valid.
+pub fn handler(ctx: Context<RemoveIssuerDelegate>) -> Result<()> {
+    let issuer_delegate = &ctx.accounts.issuer_delegate;
+    emit!(DelegateRemoved {
+        issuer: issuer_delegate.issuer,
+        delegate: issuer_delegate.delegate,
+        issued_count: issuer_delegate.issued_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
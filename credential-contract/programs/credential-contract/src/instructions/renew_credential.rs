@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, CREDENTIAL_SEED};
+use crate::error::CredentialError;
+use crate::events::CredentialRenewed;
+use crate::state::{Config, Credential, Issuer, IssuerStatus};
+
+#[derive(Accounts)]
+pub struct RenewCredential<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [CREDENTIAL_SEED, credential.issuer.as_ref(), credential.hash.as_ref()],
+        bump = credential.bump,
+        has_one = issuer @ CredentialError::IssuerMismatch,
+    )]
+    pub credential: Account<'info, Credential>,
+    #[account(
+        has_one = authority @ CredentialError::UnregisteredIssuer,
+        constraint = issuer.status == IssuerStatus::Active @ CredentialError::IssuerNotActive,
+    )]
+    pub issuer: Account<'info, Issuer>,
+    pub authority: Signer<'info>,
+}
+
+/// Pushes `valid_until` later. The previous end of the window is kept in the
+/// `CredentialRenewed` event so the full renewal history can be rebuilt.
+pub fn handler(ctx: Context<RenewCredential>, valid_until: i64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let credential = &mut ctx.accounts.credential;
+    let previous_valid_until = credential.renew(valid_until, now)?;
+
+    emit!(CredentialRenewed {
+        credential: credential.key(),
+        issuer: credential.issuer,
+        hash: credential.hash,
+        previous_valid_until,
+        valid_until,
+        renewal_count: credential.renewal_count,
+        renewed_at: now,
+    });
+    Ok(())
+}
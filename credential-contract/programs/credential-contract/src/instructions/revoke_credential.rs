@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, CREDENTIAL_SEED, DELEGATE_SEED};
+use crate::error::CredentialError;
+use crate::events::CredentialRevoked;
+use crate::state::{Config, Credential, DelegateAction, Issuer, IssuerDelegate, RevocationReason};
+
+#[derive(Accounts)]
+pub struct RevokeCredential<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [CREDENTIAL_SEED, credential.issuer.as_ref(), credential.hash.as_ref()],
+        bump = credential.bump,
+        has_one = issuer @ CredentialError::IssuerMismatch,
+    )]
+    pub credential: Account<'info, Credential>,
+    pub issuer: Account<'info, Issuer>,
+    /// Required when `authority` is a delegate rather than the issuer's root key.
+    #[account(
+        seeds = [DELEGATE_SEED, issuer.key().as_ref(), authority.key().as_ref()],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Account<'info, IssuerDelegate>>,
+    /// Issuer authority, or a delegate with revoke permission.
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevokeCredential>, reason: RevocationReason) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let authority = ctx.accounts.authority.key();
+    ctx.accounts.issuer.authorize(
+        &authority,
+        ctx.accounts.delegate.as_deref(),
+        DelegateAction::Revoke,
+        now,
+    )?;
+
+    let credential = &mut ctx.accounts.credential;
+    credential.revoke(authority, reason, now)?;
+
+    emit!(CredentialRevoked {
+        credential: credential.key(),
+        issuer: credential.issuer,
+        hash: credential.hash,
+        reason,
+        revoked_by: authority,
+        revoked_at: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::CONFIG_SEED;
+use crate::error::CredentialError;
+use crate::events::IssuerAuthorityRotated;
+use crate::state::{Config, Issuer};
+
+#[derive(Accounts)]
+pub struct RotateIssuerAuthority<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub issuer: Account<'info, Issuer>,
+    /// Current issuer authority, or the program admin when that key is lost
+    /// or compromised.
+    pub signer: Signer<'info>,
+    /// Must sign to prove the institution controls the replacement key.
+    pub new_authority: Signer<'info>,
+}
+
+/// Replaces the issuer's root key from the current slot onwards. The old key
+/// moves to `authority_history`, so credentials it issued remain attributable.
+/// Delegates it appointed stop working, since a compromised key could have
+/// appointed them; the new authority removes and re-adds the ones it trusts.
+pub fn handler(ctx: Context<RotateIssuerAuthority>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let new_authority = ctx.accounts.new_authority.key();
+    let issuer = &mut ctx.accounts.issuer;
+    require!(
+        signer == issuer.authority || signer == ctx.accounts.config.admin,
+        CredentialError::RotationNotAuthorized
+    );
+
+    let clock = Clock::get()?;
+    let previous_authority = issuer.set_authority(new_authority, clock.slot)?;
+
+    emit!(IssuerAuthorityRotated {
+        issuer: issuer.key(),
+        previous_authority,
+        authority: new_authority,
+        rotated_by: signer,
+        effective_from_slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, CREDENTIAL_SEED};
+use crate::error::CredentialError;
+use crate::events::{CredentialReinstated, CredentialSuspended};
+use crate::state::{Config, Credential, CredentialStatus, Issuer};
+
+#[derive(Accounts)]
+pub struct SetCredentialSuspended<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [CREDENTIAL_SEED, credential.issuer.as_ref(), credential.hash.as_ref()],
+        bump = credential.bump,
+        has_one = issuer @ CredentialError::IssuerMismatch,
+    )]
+    pub credential: Account<'info, Credential>,
+    #[account(has_one = authority @ CredentialError::UnregisteredIssuer)]
+    pub issuer: Account<'info, Issuer>,
+    pub authority: Signer<'info>,
+}
+
+/// Suspends an active credential or reinstates a suspended one. Revocation is
+/// final and cannot be undone here.
+pub fn handler(ctx: Context<SetCredentialSuspended>, suspended: bool) -> Result<()> {
+    let credential = &mut ctx.accounts.credential;
+    credential.set_suspended(suspended)?;
+    emit_suspension(credential.key(), credential, Clock::get()?.unix_timestamp);
+    Ok(())
+}
+
+/// Emits `CredentialSuspended` or `CredentialReinstated` for the new status.
+pub(crate) fn emit_suspension(address: Pubkey, credential: &Credential, now: i64) {
+    if credential.status == CredentialStatus::Suspended {
+        emit!(CredentialSuspended {
+            credential: address,
+            issuer: credential.issuer,
+            hash: credential.hash,
+            suspended_at: now,
+        });
+    } else {
+        emit!(CredentialReinstated {
+            credential: address,
+            issuer: credential.issuer,
+            hash: credential.hash,
+            reinstated_at: now,
+        });
+    }
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::CONFIG_SEED;
+use crate::error::CredentialError;
+use crate::events::IssuerEthAddressChanged;
+use crate::state::{Config, Issuer};
+
+#[derive(Accounts)]
+pub struct SetIssuerEthAddress<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub issuer: Account<'info, Issuer>,
+    /// Issuer authority, or the program admin for institutions that only
+    /// hold an Ethereum key.
+    pub signer: Signer<'info>,
+}
+
+/// Binds or unbinds the Ethereum address allowed to issue through
+/// `store_credential` with a secp256k1 attestation.
+pub fn handler(ctx: Context<SetIssuerEthAddress>, eth_address: Option<[u8; 20]>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let issuer = &mut ctx.accounts.issuer;
+    require!(
+        signer == issuer.authority || signer == ctx.accounts.config.admin,
+        CredentialError::NotIssuerAuthorityOrAdmin
+    );
+    let previous_eth_address = issuer.eth_address;
+    issuer.eth_address = eth_address;
+
+    emit!(IssuerEthAddressChanged {
+        issuer: issuer.key(),
+        previous_eth_address,
+        eth_address,
+        changed_by: signer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::CONFIG_SEED;
+use crate::error::CredentialError;
+use crate::events::IssuerSigningKeyChanged;
+use crate::state::{Config, Issuer};
+
+#[derive(Accounts)]
+pub struct SetIssuerSigningKey<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut, has_one = authority @ CredentialError::UnregisteredIssuer)]
+    pub issuer: Account<'info, Issuer>,
+    pub authority: Signer<'info>,
+}
+
+/// Sets or clears the Ed25519 key whose signature every new credential of
+/// this issuer must carry. Existing credentials keep their attestations.
+pub fn handler(ctx: Context<SetIssuerSigningKey>, signing_key: Option<Pubkey>) -> Result<()> {
+    let issuer = &mut ctx.accounts.issuer;
+    let previous_signing_key = issuer.signing_key;
+    issuer.signing_key = signing_key;
+
+    emit!(IssuerSigningKeyChanged {
+        issuer: issuer.key(),
+        previous_signing_key,
+        signing_key,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
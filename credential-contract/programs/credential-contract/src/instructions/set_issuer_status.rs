@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::CONFIG_SEED;
+use crate::error::CredentialError;
+use crate::events::IssuerStatusChanged;
+use crate::state::{Config, Issuer, IssuerStatus};
+
+#[derive(Accounts)]
+pub struct SetIssuerStatus<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ CredentialError::NotAdmin,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub issuer: Account<'info, Issuer>,
+    pub admin: Signer<'info>,
+}
+
+/// Suspends, revokes or reinstates an issuer. Credentials it already issued
+/// are untouched; verifiers consult the issuer status separately.
+pub fn handler(ctx: Context<SetIssuerStatus>, status: IssuerStatus) -> Result<()> {
+    let issuer = &mut ctx.accounts.issuer;
+    let previous_status = issuer.status;
+    issuer.status = status;
+
+    emit!(IssuerStatusChanged {
+        issuer: issuer.key(),
+        previous_status,
+        status,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
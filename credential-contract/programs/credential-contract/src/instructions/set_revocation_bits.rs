@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BATCH_SEED, CONFIG_SEED, REVOCATION_LIST_SEED};
+use crate::error::CredentialError;
+use crate::events::BatchCredentialsRevoked;
+use crate::state::{Config, CredentialBatch, Issuer, RevocationList};
+
+#[derive(Accounts)]
+pub struct SetRevocationBits<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [BATCH_SEED, issuer.key().as_ref(), batch.root.as_ref()],
+        bump = batch.bump,
+        has_one = issuer @ CredentialError::IssuerMismatch,
+    )]
+    pub batch: Account<'info, CredentialBatch>,
+    #[account(
+        mut,
+        seeds = [REVOCATION_LIST_SEED, batch.key().as_ref()],
+        bump = revocation_list.bump,
+    )]
+    pub revocation_list: Account<'info, RevocationList>,
+    #[account(has_one = authority @ CredentialError::UnregisteredIssuer)]
+    pub issuer: Account<'info, Issuer>,
+    pub authority: Signer<'info>,
+}
+
+/// Revokes every leaf position in `indices`; see `RevocationList::revoke_all`.
+pub fn handler(ctx: Context<SetRevocationBits>, indices: Vec<u32>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let list = &mut ctx.accounts.revocation_list;
+    list.revoke_all(&indices, ctx.accounts.batch.leaf_count, now)?;
+
+    emit!(BatchCredentialsRevoked {
+        batch: list.batch,
+        issuer: list.issuer,
+        indices,
+        revoked_count: list.revoked_count,
+        revoked_at: now,
+    });
+    Ok(())
+}
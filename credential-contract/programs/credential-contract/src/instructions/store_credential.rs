@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+
+use crate::attestation::require_attestation;
+use crate::constants::{CONFIG_SEED, CREDENTIAL_SEED, DELEGATE_SEED, TOMBSTONE_SEED};
+use crate::error::CredentialError;
+use crate::events::CredentialIssued;
+use crate::state::{
+    Config, Credential, CredentialMetadata, CredentialSchema, CredentialTerms, HashAlgorithm,
+    Issuer, IssuerDelegate, IssuerStatus,
+};
+
+#[derive(Accounts)]
+#[instruction(hash: [u8; 32])]
+pub struct StoreCredential<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    /// CHECK: only its emptiness matters; a closed credential's tombstone
+    /// lives here and blocks re-issuing the same hash.
+    #[account(
+        seeds = [TOMBSTONE_SEED, issuer.key().as_ref(), hash.as_ref()],
+        bump,
+        constraint = tombstone.data_is_empty() @ CredentialError::DuplicateCredential,
+    )]
+    pub tombstone: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Credential::INIT_SPACE,
+        seeds = [CREDENTIAL_SEED, issuer.key().as_ref(), hash.as_ref()],
+        bump
+    )]
+    pub credential: Account<'info, Credential>,
+    #[account(
+        constraint = issuer.status == IssuerStatus::Active @ CredentialError::IssuerNotActive,
+    )]
+    pub issuer: Account<'info, Issuer>,
+    #[account(
+        constraint = schema.is_available_to(&issuer.key(), &config.key())
+            @ CredentialError::SchemaNotAvailable,
+    )]
+    pub schema: Account<'info, CredentialSchema>,
+    /// Required when `authority` is a delegate rather than the issuer's root key.
+    #[account(
+        mut,
+        seeds = [DELEGATE_SEED, issuer.key().as_ref(), authority.key().as_ref()],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Account<'info, IssuerDelegate>>,
+    /// CHECK: the instructions sysvar; required when the issuer has a signing key.
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+    /// Issuer authority or delegate; any relayer when the issuance carries a
+    /// secp256k1 attestation from the issuer's Ethereum address.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<StoreCredential>,
+    hash: [u8; 32],
+    algorithm: HashAlgorithm,
+    subject: Option<Pubkey>,
+    valid_from: Option<i64>,
+    valid_until: Option<i64>,
+    metadata: Option<CredentialMetadata>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let authority = ctx.accounts.authority.key();
+    let terms = CredentialTerms {
+        schema: ctx.accounts.schema.key(),
+        hash,
+        algorithm,
+        subject,
+        valid_from,
+        valid_until,
+        metadata,
+    };
+    terms.validate(clock.unix_timestamp)?;
+    let attestation = require_attestation(
+        &ctx.accounts.issuer,
+        ctx.accounts.instructions.as_deref(),
+        &terms,
+        None,
+    )?;
+    ctx.accounts.issuer.authorize_issuance(
+        &authority,
+        ctx.accounts.delegate.as_deref(),
+        attestation.as_ref(),
+        terms.schema,
+        clock.unix_timestamp,
+    )?;
+    if let Some(delegate) = ctx.accounts.delegate.as_mut() {
+        delegate.issued_count += 1;
+    }
+
+    let credential = &mut ctx.accounts.credential;
+    credential.set_inner(Credential {
+        attestation,
+        ..Credential::new(
+            ctx.accounts.issuer.key(),
+            authority,
+            &terms,
+            None,
+            &clock,
+            ctx.bumps.credential,
+        )
+    });
+
+    emit_issued(credential.key(), credential);
+    Ok(())
+}
+
+pub(crate) fn emit_issued(address: Pubkey, credential: &Credential) {
+    emit!(CredentialIssued {
+        credential: address,
+        issuer: credential.issuer,
+        issued_by: credential.issued_by,
+        schema: credential.schema,
+        subject: credential.subject,
+        hash: credential.hash,
+        algorithm: credential.algorithm,
+        metadata: credential.metadata.clone(),
+        attestation: credential.attestation,
+        valid_from: credential.valid_from,
+        valid_until: credential.valid_until,
+        supersedes: credential.supersedes,
+        issued_at: credential.issued_at,
+    });
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BATCH_SEED, CONFIG_SEED, MAX_BATCH_LEAVES, REVOCATION_LIST_SEED};
+use crate::error::CredentialError;
+use crate::events::CredentialBatchIssued;
+use crate::state::{Config, CredentialBatch, HashAlgorithm, Issuer, IssuerStatus, RevocationList};
+
+#[derive(Accounts)]
+#[instruction(root: [u8; 32], leaf_count: u32)]
+pub struct StoreCredentialBatch<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CredentialBatch::INIT_SPACE,
+        seeds = [BATCH_SEED, issuer.key().as_ref(), root.as_ref()],
+        bump
+    )]
+    pub batch: Account<'info, CredentialBatch>,
+    #[account(
+        init,
+        payer = authority,
+        space = RevocationList::space(leaf_count.min(MAX_BATCH_LEAVES)),
+        seeds = [REVOCATION_LIST_SEED, batch.key().as_ref()],
+        bump
+    )]
+    pub revocation_list: Account<'info, RevocationList>,
+    #[account(
+        has_one = authority @ CredentialError::UnregisteredIssuer,
+        constraint = issuer.status == IssuerStatus::Active @ CredentialError::IssuerNotActive,
+        constraint = issuer.signing_key.is_none() @ CredentialError::SigningKeyPreventsBatch,
+    )]
+    pub issuer: Account<'info, Issuer>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<StoreCredentialBatch>,
+    root: [u8; 32],
+    leaf_count: u32,
+    algorithm: HashAlgorithm,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let batch = &mut ctx.accounts.batch;
+    batch.set_inner(CredentialBatch::new(
+        ctx.accounts.issuer.key(),
+        ctx.accounts.authority.key(),
+        root,
+        leaf_count,
+        algorithm,
+        &clock,
+        ctx.bumps.batch,
+    )?);
+
+    ctx.accounts.revocation_list.set_inner(RevocationList::new(
+        batch.key(),
+        batch.issuer,
+        leaf_count,
+        clock.unix_timestamp,
+        ctx.bumps.revocation_list,
+    ));
+
+    emit!(CredentialBatchIssued {
+        batch: batch.key(),
+        issuer: batch.issuer,
+        issued_by: batch.issued_by,
+        root,
+        leaf_count,
+        algorithm,
+        issued_at: clock.unix_timestamp,
+    });
+    Ok(())
+}
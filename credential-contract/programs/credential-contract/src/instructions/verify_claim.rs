@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::claims::{self, DisclosedClaim};
+use crate::constants::CREDENTIAL_SEED;
+use crate::error::CredentialError;
+use crate::state::{Credential, HashAlgorithm, Issuer, VerificationResult};
+
+#[derive(Accounts)]
+#[instruction(hash: [u8; 32])]
+pub struct VerifyClaim<'info> {
+    pub issuer: Account<'info, Issuer>,
+    #[account(
+        seeds = [CREDENTIAL_SEED, issuer.key().as_ref(), hash.as_ref()],
+        bump = credential.bump,
+        has_one = issuer @ CredentialError::IssuerMismatch,
+    )]
+    pub credential: Account<'info, Credential>,
+}
+
+/// Proves each disclosed claim against the credential's claims root, then
+/// reports the credential's status as `verify_credential` would. Fails if any
+/// claim does not open against the root or `claim_count` is not the count
+/// the root commits to.
+pub fn handler(
+    ctx: Context<VerifyClaim>,
+    _hash: [u8; 32],
+    claim_count: u32,
+    claims: Vec<DisclosedClaim>,
+) -> Result<VerificationResult> {
+    let credential = &ctx.accounts.credential;
+    require!(
+        credential.algorithm == HashAlgorithm::ClaimsRoot,
+        CredentialError::NotClaimsCommitment
+    );
+    require!(!claims.is_empty(), CredentialError::InvalidClaimProof);
+    for claim in &claims {
+        require!(
+            claims::verify(&credential.hash, claim_count, claim),
+            CredentialError::InvalidClaimProof
+        );
+    }
+
+    Ok(VerificationResult::for_credential(
+        credential.key(),
+        credential,
+        &ctx.accounts.issuer,
+        Clock::get()?.unix_timestamp,
+    ))
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::CREDENTIAL_SEED;
+use crate::error::CredentialError;
+use crate::poseidon;
+use crate::state::{Credential, HashAlgorithm, Issuer, VerificationResult};
+
+#[derive(Accounts)]
+#[instruction(hash: [u8; 32])]
+pub struct VerifyCommitmentOpening<'info> {
+    pub issuer: Account<'info, Issuer>,
+    #[account(
+        seeds = [CREDENTIAL_SEED, issuer.key().as_ref(), hash.as_ref()],
+        bump = credential.bump,
+        has_one = issuer @ CredentialError::IssuerMismatch,
+    )]
+    pub credential: Account<'info, Credential>,
+}
+
+/// Recomputes a Poseidon credential's commitment from its opening with the
+/// `sol_poseidon` syscall, then reports the credential's status as
+/// `verify_credential` would.
+pub fn handler(
+    ctx: Context<VerifyCommitmentOpening>,
+    _hash: [u8; 32],
+    salt: [u8; 32],
+    values: Vec<[u8; 32]>,
+) -> Result<VerificationResult> {
+    let credential = &ctx.accounts.credential;
+    require!(
+        credential.algorithm == HashAlgorithm::Poseidon,
+        CredentialError::NotPoseidonCommitment
+    );
+    require!(
+        poseidon::commit(&salt, &values)? == credential.hash,
+        CredentialError::InvalidCommitmentOpening
+    );
+
+    Ok(VerificationResult::for_credential(
+        credential.key(),
+        credential,
+        &ctx.accounts.issuer,
+        Clock::get()?.unix_timestamp,
+    ))
+}
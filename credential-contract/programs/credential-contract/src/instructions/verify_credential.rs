@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CREDENTIAL_SEED, TOMBSTONE_SEED};
+use crate::state::{Credential, Issuer, Tombstone, VerificationResult};
+
+#[derive(Accounts)]
+#[instruction(hash: [u8; 32])]
+pub struct VerifyCredential<'info> {
+    pub issuer: Account<'info, Issuer>,
+    /// CHECK: address is pinned by the seeds; the account may legitimately be
+    /// uninitialized, which is reported as `NotFound` rather than failing.
+    #[account(seeds = [CREDENTIAL_SEED, issuer.key().as_ref(), hash.as_ref()], bump)]
+    pub credential: UncheckedAccount<'info>,
+    /// CHECK: address is pinned by the seeds; it exists only once the
+    /// credential has been closed, which is then reported as `Closed`.
+    #[account(seeds = [TOMBSTONE_SEED, issuer.key().as_ref(), hash.as_ref()], bump)]
+    pub tombstone: UncheckedAccount<'info>,
+}
+
+/// Read-only check of a credential against its issuer and the current clock.
+/// Anchor writes the result to return data, so it can be read from a
+/// simulated transaction or by a calling program.
+pub fn handler(ctx: Context<VerifyCredential>, _hash: [u8; 32]) -> Result<VerificationResult> {
+    let now = Clock::get()?.unix_timestamp;
+    let issuer = &ctx.accounts.issuer;
+    let info = ctx.accounts.credential.to_account_info();
+
+    if info.owner != &crate::ID || info.data_is_empty() {
+        let tombstone = ctx.accounts.tombstone.to_account_info();
+        if tombstone.owner == &crate::ID && !tombstone.data_is_empty() {
+            let tombstone = Tombstone::try_deserialize(&mut &tombstone.try_borrow_data()?[..])?;
+            return Ok(VerificationResult::closed(
+                info.key(),
+                &tombstone,
+                issuer.status,
+                now,
+            ));
+        }
+        return Ok(VerificationResult::not_found(
+            info.key(),
+            issuer.key(),
+            issuer.status,
+            now,
+        ));
+    }
+
+    let credential = Credential::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    Ok(VerificationResult::for_credential(
+        info.key(),
+        &credential,
+        issuer,
+        now,
+    ))
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BATCH_SEED, MAX_MERKLE_DEPTH, REVOCATION_LIST_SEED};
+use crate::error::CredentialError;
+use crate::merkle;
+use crate::state::{CredentialBatch, Issuer, RevocationList, VerificationResult};
+
+#[derive(Accounts)]
+pub struct VerifyInBatch<'info> {
+    pub issuer: Account<'info, Issuer>,
+    #[account(
+        seeds = [BATCH_SEED, issuer.key().as_ref(), batch.root.as_ref()],
+        bump = batch.bump,
+        has_one = issuer @ CredentialError::IssuerMismatch,
+    )]
+    pub batch: Account<'info, CredentialBatch>,
+    #[account(seeds = [REVOCATION_LIST_SEED, batch.key().as_ref()], bump = revocation_list.bump)]
+    pub revocation_list: Account<'info, RevocationList>,
+}
+
+/// Checks that `leaf` sits at `index` under the batch root and that its bit in
+/// the batch's revocation list is clear. A proof that does not reach the root
+/// is reported as `NotFound`, mirroring `verify_credential`.
+pub fn handler(
+    ctx: Context<VerifyInBatch>,
+    leaf: [u8; 32],
+    index: u32,
+    proof: Vec<[u8; 32]>,
+) -> Result<VerificationResult> {
+    require!(
+        proof.len() <= MAX_MERKLE_DEPTH,
+        CredentialError::InvalidMerkleProof
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let batch = &ctx.accounts.batch;
+    let included = merkle::compute_root(&leaf, index, batch.leaf_count, &proof) == Some(batch.root);
+
+    let revoked = ctx.accounts.revocation_list.is_revoked(index);
+
+    Ok(VerificationResult::for_batch(
+        batch.key(),
+        batch,
+        included,
+        revoked,
+        &ctx.accounts.issuer,
+        now,
+    ))
+}
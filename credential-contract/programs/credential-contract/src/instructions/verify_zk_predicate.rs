@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CONFIG_SEED, PREDICATE_NULLIFIER_SEED, PREDICATE_SEED};
+use crate::error::CredentialError;
+use crate::events::ZkPredicateVerified;
+use crate::groth16::{self, Groth16Proof};
+use crate::state::{
+    Config, Credential, HashAlgorithm, Issuer, PredicateNullifier, PredicateVerification,
+    PredicateVerifier, VerificationResult, VerificationStatus,
+};
+
+/// Remaining accounts: an `(issuer, credential)` pair for each credential in
+/// the anonymity set, in the order of the circuit's commitment inputs.
+#[derive(Accounts)]
+#[instruction(proof: Groth16Proof, challenge: [u8; 32])]
+pub struct VerifyZkPredicate<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ CredentialError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [PREDICATE_SEED, verifier.predicate_id.as_bytes()],
+        bump = verifier.bump,
+        constraint = verifier.is_complete() @ CredentialError::VerifyingKeyIncomplete,
+    )]
+    pub verifier: Account<'info, PredicateVerifier>,
+    /// Consumes `challenge`; a replayed proof fails here.
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PredicateNullifier::INIT_SPACE,
+        seeds = [PREDICATE_NULLIFIER_SEED, verifier.key().as_ref(), challenge.as_ref()],
+        bump
+    )]
+    pub nullifier: Account<'info, PredicateNullifier>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Succeeds only if `proof` shows that the holder can open one of the given
+/// credentials' Poseidon commitments to values satisfying the predicate's
+/// registered parameters, without revealing which credential. Every
+/// credential in the set must use the predicate's schema and currently verify
+/// as `Valid`, so whichever one the holder opened is too.
+///
+/// The proof is bound to `challenge`, which is consumed: a relying party
+/// issues a fresh challenge, and a proof is accepted once.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VerifyZkPredicate<'info>>,
+    proof: Groth16Proof,
+    challenge: [u8; 32],
+) -> Result<PredicateVerification> {
+    let verifier = &ctx.accounts.verifier;
+    let credential_count = usize::from(verifier.credential_count);
+    require!(
+        ctx.remaining_accounts.len() == 2 * credential_count,
+        CredentialError::PredicateInputMismatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let mut public_inputs = Vec::with_capacity(usize::from(verifier.input_count));
+    let mut credentials = Vec::with_capacity(credential_count);
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let issuer = Account::<Issuer>::try_from(&pair[0])?;
+        let credential = Account::<Credential>::try_from(&pair[1])?;
+        require_keys_eq!(
+            credential.issuer,
+            issuer.key(),
+            CredentialError::IssuerMismatch
+        );
+        require_keys_eq!(
+            credential.schema,
+            verifier.schema,
+            CredentialError::PredicateInputMismatch
+        );
+        require!(
+            credential.algorithm == HashAlgorithm::Poseidon,
+            CredentialError::NotPoseidonCommitment
+        );
+        let result =
+            VerificationResult::for_credential(credential.key(), &credential, &issuer, now);
+        require!(
+            result.status == VerificationStatus::Valid,
+            CredentialError::PredicateCredentialNotValid
+        );
+        public_inputs.push(credential.hash);
+        credentials.push(credential.key());
+    }
+    public_inputs.extend_from_slice(&verifier.parameters);
+    public_inputs.push(challenge);
+
+    groth16::verify(&verifier.key, &verifier.ic, &proof, &public_inputs)?;
+
+    let payer = ctx.accounts.payer.key();
+    ctx.accounts.nullifier.set_inner(PredicateNullifier {
+        verifier: verifier.key(),
+        challenge,
+        consumed_by: payer,
+        consumed_at: now,
+        bump: ctx.bumps.nullifier,
+    });
+
+    emit!(ZkPredicateVerified {
+        verifier: verifier.key(),
+        challenge,
+        credentials: credentials.clone(),
+        verified_by: payer,
+        verified_at: now,
+    });
+    Ok(PredicateVerification {
+        verifier: verifier.key(),
+        schema: verifier.schema,
+        parameters: verifier.parameters.clone(),
+        challenge,
+        credentials,
+        verified_at: now,
+    })
+}
@@ -1,30 +1,260 @@
+// The `#[program]` expansion references cfgs and `AccountInfo::realloc`, which
+// newer toolchains flag; neither is actionable from this crate.
+#![allow(unexpected_cfgs, deprecated)]
+
+pub mod attestation;
+pub mod claims;
+pub mod constants;
+pub mod error;
+pub mod events;
+#[cfg(feature = "cpi")]
+pub mod gate;
+pub mod groth16;
+pub mod instructions;
+pub mod merkle;
+pub mod poseidon;
+pub mod state;
+
 use anchor_lang::prelude::*;
 
+pub use constants::*;
+pub use error::*;
+pub use events::*;
+pub use instructions::*;
+pub use state::*;
+
 declare_id!("4TzHgfTzZUjvCDNvj19qNSj1UgZYNQHZUZkiTZrTCN9m");
 
 #[program]
 pub mod credential_contract {
     use super::*;
 
-    pub fn store_credential(ctx: Context<StoreCredential>, hash: String) -> Result<()> {
-        let credential = &mut ctx.accounts.credential;
-        credential.hash = hash;
-        Ok(())
+    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+        initialize::handler(ctx)
     }
 
-    
-}
+    pub fn nominate_admin(ctx: Context<NominateAdmin>, new_admin: Option<Pubkey>) -> Result<()> {
+        nominate_admin::handler(ctx, new_admin)
+    }
 
-#[derive(Accounts)]
-pub struct StoreCredential<'info> {
-    #[account(init, payer = authority, space = 64)]
-    pub credential: Account<'info, Credential>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        accept_admin::handler(ctx)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        set_paused::handler(ctx, paused)
+    }
+
+    pub fn register_issuer(
+        ctx: Context<RegisterIssuer>,
+        authority: Pubkey,
+        name: String,
+        website: String,
+        did: String,
+    ) -> Result<()> {
+        register_issuer::handler(ctx, authority, name, website, did)
+    }
+
+    pub fn set_issuer_status(ctx: Context<SetIssuerStatus>, status: IssuerStatus) -> Result<()> {
+        set_issuer_status::handler(ctx, status)
+    }
+
+    pub fn rotate_issuer_authority(ctx: Context<RotateIssuerAuthority>) -> Result<()> {
+        rotate_issuer_authority::handler(ctx)
+    }
+
+    pub fn set_issuer_signing_key(
+        ctx: Context<SetIssuerSigningKey>,
+        signing_key: Option<Pubkey>,
+    ) -> Result<()> {
+        set_issuer_signing_key::handler(ctx, signing_key)
+    }
+
+    pub fn set_issuer_eth_address(
+        ctx: Context<SetIssuerEthAddress>,
+        eth_address: Option<[u8; 20]>,
+    ) -> Result<()> {
+        set_issuer_eth_address::handler(ctx, eth_address)
+    }
+
+    pub fn add_issuer_delegate(
+        ctx: Context<AddIssuerDelegate>,
+        delegate: Pubkey,
+        scope: DelegateScope,
+    ) -> Result<()> {
+        add_issuer_delegate::handler(ctx, delegate, scope)
+    }
+
+    pub fn remove_issuer_delegate(ctx: Context<RemoveIssuerDelegate>) -> Result<()> {
+        remove_issuer_delegate::handler(ctx)
+    }
+
+    pub fn create_issuer_multisig(
+        ctx: Context<CreateIssuerMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        create_issuer_multisig::handler(ctx, signers, threshold)
+    }
+
+    pub fn propose_action(ctx: Context<ProposeAction>, action: MultisigAction) -> Result<()> {
+        propose_action::handler(ctx, action)
+    }
+
+    pub fn approve_action(ctx: Context<ApproveAction>) -> Result<()> {
+        approve_action::handler(ctx)
+    }
+
+    pub fn cancel_action(ctx: Context<CancelAction>) -> Result<()> {
+        cancel_action::handler(ctx)
+    }
+
+    pub fn execute_action(ctx: Context<ExecuteAction>) -> Result<()> {
+        execute_action::handler(ctx)
+    }
 
-#[account]
-pub struct Credential {
-    pub hash: String,
-}
\ No newline at end of file
+    pub fn register_schema(
+        ctx: Context<RegisterSchema>,
+        schema_id: String,
+        version: u16,
+        name: String,
+        content_hash: [u8; 32],
+        uri: String,
+    ) -> Result<()> {
+        register_schema::handler(ctx, schema_id, version, name, content_hash, uri)
+    }
+
+    pub fn register_predicate_verifier(
+        ctx: Context<RegisterPredicateVerifier>,
+        predicate_id: String,
+        credential_count: u8,
+        parameters: Vec<[u8; 32]>,
+        key: groth16::VerifyingKey,
+    ) -> Result<()> {
+        register_predicate_verifier::handler(ctx, predicate_id, credential_count, parameters, key)
+    }
+
+    pub fn extend_predicate_verifier(
+        ctx: Context<ExtendPredicateVerifier>,
+        ic: Vec<[u8; 64]>,
+    ) -> Result<()> {
+        extend_predicate_verifier::handler(ctx, ic)
+    }
+
+    pub fn store_credential(
+        ctx: Context<StoreCredential>,
+        hash: [u8; 32],
+        algorithm: HashAlgorithm,
+        subject: Option<Pubkey>,
+        valid_from: Option<i64>,
+        valid_until: Option<i64>,
+        metadata: Option<CredentialMetadata>,
+    ) -> Result<()> {
+        store_credential::handler(
+            ctx,
+            hash,
+            algorithm,
+            subject,
+            valid_from,
+            valid_until,
+            metadata,
+        )
+    }
+
+    pub fn reissue_credential(
+        ctx: Context<ReissueCredential>,
+        hash: [u8; 32],
+        algorithm: HashAlgorithm,
+        subject: Option<Pubkey>,
+        valid_from: Option<i64>,
+        valid_until: Option<i64>,
+        metadata: Option<CredentialMetadata>,
+    ) -> Result<()> {
+        reissue_credential::handler(
+            ctx,
+            hash,
+            algorithm,
+            subject,
+            valid_from,
+            valid_until,
+            metadata,
+        )
+    }
+
+    pub fn revoke_credential(
+        ctx: Context<RevokeCredential>,
+        reason: RevocationReason,
+    ) -> Result<()> {
+        revoke_credential::handler(ctx, reason)
+    }
+
+    pub fn renew_credential(ctx: Context<RenewCredential>, valid_until: i64) -> Result<()> {
+        renew_credential::handler(ctx, valid_until)
+    }
+
+    pub fn set_credential_suspended(
+        ctx: Context<SetCredentialSuspended>,
+        suspended: bool,
+    ) -> Result<()> {
+        set_credential_suspended::handler(ctx, suspended)
+    }
+
+    pub fn verify_credential(
+        ctx: Context<VerifyCredential>,
+        hash: [u8; 32],
+    ) -> Result<VerificationResult> {
+        verify_credential::handler(ctx, hash)
+    }
+
+    pub fn verify_claim(
+        ctx: Context<VerifyClaim>,
+        hash: [u8; 32],
+        claim_count: u32,
+        claims: Vec<claims::DisclosedClaim>,
+    ) -> Result<VerificationResult> {
+        verify_claim::handler(ctx, hash, claim_count, claims)
+    }
+
+    pub fn verify_commitment_opening(
+        ctx: Context<VerifyCommitmentOpening>,
+        hash: [u8; 32],
+        salt: [u8; 32],
+        values: Vec<[u8; 32]>,
+    ) -> Result<VerificationResult> {
+        verify_commitment_opening::handler(ctx, hash, salt, values)
+    }
+
+    pub fn verify_zk_predicate<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VerifyZkPredicate<'info>>,
+        proof: groth16::Groth16Proof,
+        challenge: [u8; 32],
+    ) -> Result<PredicateVerification> {
+        verify_zk_predicate::handler(ctx, proof, challenge)
+    }
+
+    pub fn store_credential_batch(
+        ctx: Context<StoreCredentialBatch>,
+        root: [u8; 32],
+        leaf_count: u32,
+        algorithm: HashAlgorithm,
+    ) -> Result<()> {
+        store_credential_batch::handler(ctx, root, leaf_count, algorithm)
+    }
+
+    pub fn verify_in_batch(
+        ctx: Context<VerifyInBatch>,
+        leaf: [u8; 32],
+        index: u32,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<VerificationResult> {
+        verify_in_batch::handler(ctx, leaf, index, proof)
+    }
+
+    pub fn set_revocation_bits(ctx: Context<SetRevocationBits>, indices: Vec<u32>) -> Result<()> {
+        set_revocation_bits::handler(ctx, indices)
+    }
+
+    pub fn close_credential(ctx: Context<CloseCredential>) -> Result<()> {
+        close_credential::handler(ctx)
+    }
+}
@@ -0,0 +1,50 @@
+//! Positional binary Merkle tree over credential hashes, shared by batch
+//! issuance on-chain and the tree builder in `credential-client`.
+//!
+//! Leaves and interior nodes are SHA-256 hashed under distinct one-byte
+//! prefixes so a leaf can never be passed off as an interior node. When a
+//! level has an odd number of nodes, the last one is carried up unchanged
+//! rather than paired with itself, so every tree shape has exactly one root.
+
+use anchor_lang::solana_program::hash::hashv;
+
+pub const LEAF_PREFIX: &[u8] = &[0x00];
+pub const NODE_PREFIX: &[u8] = &[0x01];
+
+pub fn hash_leaf(leaf: &[u8; 32]) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, leaf]).to_bytes()
+}
+
+pub fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// Recomputes the root from `leaf` at position `index` of a tree with
+/// `leaf_count` leaves. Returns `None` if the proof has the wrong length or
+/// the index is out of range.
+pub fn compute_root(
+    leaf: &[u8; 32],
+    index: u32,
+    leaf_count: u32,
+    proof: &[[u8; 32]],
+) -> Option<[u8; 32]> {
+    if index >= leaf_count {
+        return None;
+    }
+
+    let mut node = hash_leaf(leaf);
+    let mut index = index;
+    let mut width = leaf_count;
+    let mut siblings = proof.iter();
+    while width > 1 {
+        if index % 2 == 1 {
+            node = hash_node(siblings.next()?, &node);
+        } else if index + 1 < width {
+            node = hash_node(&node, siblings.next()?);
+        }
+        index /= 2;
+        width = width.div_ceil(2);
+    }
+
+    siblings.next().is_none().then_some(node)
+}
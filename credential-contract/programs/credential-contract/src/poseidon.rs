@@ -0,0 +1,114 @@
+//! Poseidon commitments for `HashAlgorithm::Poseidon` credentials, the form
+//! zero-knowledge circuits can open cheaply.
+//!
+//! A commitment is Poseidon over BN254 (x^5 S-box, circomlib-compatible
+//! constants) of big-endian field elements:
+//!
+//! `commitment = Poseidon(salt, value_1, ..., value_n)` with `n <= 11`
+//!
+//! On-chain this runs through the `sol_poseidon` syscall; off-chain the same
+//! code runs natively, so issuers and programs always agree. Reference
+//! vectors shared with circomlib:
+//!
+//! - `Poseidon(1)       = 0x29176100eaa962bdc1fe6c654d6a3c130e96a4d1168b33848b897dc502820133`
+//! - `Poseidon(1, 2)    = 0x115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a`
+//! - `Poseidon(1, 2, 3) = 0x0e7732d89e6939c0ff03d5e58dab6302f3230e269dc5b968f725df34ab36d732`
+
+use anchor_lang::prelude::*;
+use solana_poseidon::{hashv, Endianness, Parameters};
+
+use crate::constants::{BN254_SCALAR_MODULUS, MAX_POSEIDON_INPUTS};
+use crate::error::CredentialError;
+
+/// Poseidon hash of 1 to `MAX_POSEIDON_INPUTS` big-endian field elements.
+pub fn hash(inputs: &[[u8; 32]]) -> Result<[u8; 32]> {
+    require!(
+        !inputs.is_empty() && inputs.len() <= MAX_POSEIDON_INPUTS,
+        CredentialError::InvalidCommitmentOpening
+    );
+    for input in inputs {
+        require!(
+            input[..] < BN254_SCALAR_MODULUS[..],
+            CredentialError::InvalidFieldElement
+        );
+    }
+    let inputs: Vec<&[u8]> = inputs.iter().map(|input| input.as_slice()).collect();
+    let digest = hashv(Parameters::Bn254X5, Endianness::BigEndian, &inputs)
+        .map_err(|_| CredentialError::InvalidFieldElement)?;
+    Ok(digest.to_bytes())
+}
+
+/// Commitment to `values` blinded by `salt`, itself a random field element.
+pub fn commit(salt: &[u8; 32], values: &[[u8; 32]]) -> Result<[u8; 32]> {
+    let inputs: Vec<[u8; 32]> = std::iter::once(*salt)
+        .chain(values.iter().copied())
+        .collect();
+    hash(&inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(value: u8) -> [u8; 32] {
+        let mut element = [0u8; 32];
+        element[31] = value;
+        element
+    }
+
+    fn hex(bytes: &[u8; 32]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn matches_circomlib_vectors() {
+        let vectors = [
+            (
+                vec![field(1)],
+                "29176100eaa962bdc1fe6c654d6a3c130e96a4d1168b33848b897dc502820133",
+            ),
+            (
+                vec![field(1), field(2)],
+                "115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a",
+            ),
+            (
+                vec![field(1), field(2), field(3)],
+                "0e7732d89e6939c0ff03d5e58dab6302f3230e269dc5b968f725df34ab36d732",
+            ),
+        ];
+        for (inputs, expected) in vectors {
+            assert_eq!(hex(&hash(&inputs).unwrap()), expected);
+        }
+    }
+
+    #[test]
+    fn commit_prepends_the_salt() {
+        assert_eq!(
+            commit(&field(1), &[field(2), field(3)]).unwrap(),
+            hash(&[field(1), field(2), field(3)]).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_inputs_at_or_above_the_modulus() {
+        let mut below = BN254_SCALAR_MODULUS;
+        below[31] -= 1;
+        assert!(hash(&[below]).is_ok());
+
+        let mut above = BN254_SCALAR_MODULUS;
+        above[31] += 1;
+        for input in [BN254_SCALAR_MODULUS, above, [0xff; 32]] {
+            let expected: Error = CredentialError::InvalidFieldElement.into();
+            assert_eq!(hash(&[field(1), input]).unwrap_err(), expected);
+            assert_eq!(commit(&input, &[field(1)]).unwrap_err(), expected);
+        }
+    }
+
+    #[test]
+    fn rejects_empty_and_oversized_inputs() {
+        assert!(hash(&[]).is_err());
+        assert!(hash(&vec![field(1); MAX_POSEIDON_INPUTS]).is_ok());
+        assert!(hash(&vec![field(1); MAX_POSEIDON_INPUTS + 1]).is_err());
+        assert!(commit(&field(1), &vec![field(1); MAX_POSEIDON_INPUTS]).is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_BATCH_LEAVES;
+use crate::error::CredentialError;
+use crate::state::HashAlgorithm;
+
+/// Anchors a whole cohort of credentials with a single Merkle root; see
+/// `crate::merkle` for the tree layout. Individual credentials are proven with
+/// `verify_in_batch` instead of having accounts of their own.
+#[account]
+#[derive(InitSpace)]
+pub struct CredentialBatch {
+    pub issuer: Pubkey,
+    pub issued_by: Pubkey,
+    pub root: [u8; 32],
+    pub leaf_count: u32,
+    /// Algorithm every leaf hash in the batch was produced with.
+    pub algorithm: HashAlgorithm,
+    pub issued_at: i64,
+    pub issued_slot: u64,
+    pub bump: u8,
+}
+
+impl CredentialBatch {
+    /// Fails unless `root` is non-zero and the batch holds between 1 and
+    /// `MAX_BATCH_LEAVES` credentials.
+    pub fn new(
+        issuer: Pubkey,
+        issued_by: Pubkey,
+        root: [u8; 32],
+        leaf_count: u32,
+        algorithm: HashAlgorithm,
+        clock: &Clock,
+        bump: u8,
+    ) -> Result<Self> {
+        require!(root.iter().any(|b| *b != 0), CredentialError::InvalidHash);
+        require!(
+            leaf_count > 0 && leaf_count <= MAX_BATCH_LEAVES,
+            CredentialError::InvalidBatchSize
+        );
+        Ok(Self {
+            issuer,
+            issued_by,
+            root,
+            leaf_count,
+            algorithm,
+            issued_at: clock.unix_timestamp,
+            issued_slot: clock.slot,
+            bump,
+        })
+    }
+}
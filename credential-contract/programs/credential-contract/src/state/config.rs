@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Program-wide settings, stored in the singleton PDA `[CONFIG_SEED]`.
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    /// Key allowed to register issuers, change their status and pause the program.
+    pub admin: Pubkey,
+    /// Nominated successor; becomes `admin` once it signs `accept_admin`.
+    pub pending_admin: Option<Pubkey>,
+    /// When set, every state-changing instruction except the admin controls fails.
+    pub paused: bool,
+    pub version: u8,
+    pub bump: u8,
+}
@@ -0,0 +1,286 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BN254_SCALAR_MODULUS, MAX_METADATA_URI_LEN};
+use crate::error::CredentialError;
+use crate::state::Tombstone;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Credential {
+    /// `Issuer` registry account the credential was issued under.
+    pub issuer: Pubkey,
+    /// Key that signed and paid for issuance: the issuer authority or a delegate.
+    pub issued_by: Pubkey,
+    /// `CredentialSchema` describing the document `hash` commits to.
+    pub schema: Pubkey,
+    /// Holder the credential was issued to, if the issuer bound one.
+    pub subject: Option<Pubkey>,
+    pub hash: [u8; 32],
+    pub algorithm: HashAlgorithm,
+    /// Off-chain document behind the credential, if the issuer published one.
+    pub metadata: Option<CredentialMetadata>,
+    /// Issuer signing-key signature over the credential, when the issuer
+    /// requires one.
+    pub attestation: Option<IssuerAttestation>,
+    /// Unix timestamp from the `Clock` sysvar at issuance.
+    pub issued_at: i64,
+    pub issued_slot: u64,
+    pub status: CredentialStatus,
+    pub revoked_at: Option<i64>,
+    /// Issuer authority or delegate that revoked the credential.
+    pub revoked_by: Option<Pubkey>,
+    pub revocation_reason: Option<RevocationReason>,
+    /// Start of the validity window; `None` means valid from issuance.
+    pub valid_from: Option<i64>,
+    /// End of the validity window; `None` means the credential never expires.
+    pub valid_until: Option<i64>,
+    /// Number of times `renew_credential` has extended `valid_until`.
+    pub renewal_count: u16,
+    pub last_renewed_at: Option<i64>,
+    /// Earlier credential this one corrects, set by `reissue_credential`.
+    pub supersedes: Option<Pubkey>,
+    /// Newer credential that replaced this one; follow it to reach the current version.
+    pub superseded_by: Option<Pubkey>,
+    pub bump: u8,
+}
+
+/// Everything an issuer commits to when creating a credential.
+pub struct CredentialTerms {
+    pub schema: Pubkey,
+    pub hash: [u8; 32],
+    pub algorithm: HashAlgorithm,
+    pub subject: Option<Pubkey>,
+    pub valid_from: Option<i64>,
+    pub valid_until: Option<i64>,
+    pub metadata: Option<CredentialMetadata>,
+}
+
+/// Where to fetch a credential's document and the digest it must match.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct CredentialMetadata {
+    /// IPFS CID, Arweave transaction id or HTTPS URL of the document.
+    #[max_len(MAX_METADATA_URI_LEN)]
+    pub metadata_uri: String,
+    /// SHA-256 of the document bytes served at `metadata_uri`.
+    pub content_digest: [u8; 32],
+}
+
+impl CredentialTerms {
+    /// Checks the digest and metadata, and that any window ends after it
+    /// starts and after `now`.
+    pub fn validate(&self, now: i64) -> Result<()> {
+        self.algorithm.validate_digest(&self.hash)?;
+        if let Some(metadata) = &self.metadata {
+            require!(
+                !metadata.metadata_uri.is_empty()
+                    && metadata.metadata_uri.len() <= MAX_METADATA_URI_LEN
+                    && metadata.content_digest.iter().any(|b| *b != 0),
+                CredentialError::InvalidMetadata
+            );
+        }
+        if let Some(until) = self.valid_until {
+            require!(until > now, CredentialError::InvalidValidityWindow);
+            if let Some(from) = self.valid_from {
+                require!(until > from, CredentialError::InvalidValidityWindow);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Credential {
+    pub fn new(
+        issuer: Pubkey,
+        issued_by: Pubkey,
+        terms: &CredentialTerms,
+        supersedes: Option<Pubkey>,
+        clock: &Clock,
+        bump: u8,
+    ) -> Self {
+        Self {
+            issuer,
+            issued_by,
+            schema: terms.schema,
+            subject: terms.subject,
+            hash: terms.hash,
+            algorithm: terms.algorithm,
+            metadata: terms.metadata.clone(),
+            attestation: None,
+            issued_at: clock.unix_timestamp,
+            issued_slot: clock.slot,
+            status: CredentialStatus::Active,
+            revoked_at: None,
+            revoked_by: None,
+            revocation_reason: None,
+            valid_from: terms.valid_from,
+            valid_until: terms.valid_until,
+            renewal_count: 0,
+            last_renewed_at: None,
+            supersedes,
+            superseded_by: None,
+            bump,
+        }
+    }
+
+    /// Marks the credential revoked by `revoked_by` at unix time `now`.
+    pub fn revoke(&mut self, revoked_by: Pubkey, reason: RevocationReason, now: i64) -> Result<()> {
+        require!(
+            self.status != CredentialStatus::Revoked,
+            CredentialError::CredentialRevoked
+        );
+        self.status = CredentialStatus::Revoked;
+        self.revoked_at = Some(now);
+        self.revoked_by = Some(revoked_by);
+        self.revocation_reason = Some(reason);
+        Ok(())
+    }
+
+    /// Extends `valid_until` to a later time and returns the previous end.
+    pub fn renew(&mut self, valid_until: i64, now: i64) -> Result<i64> {
+        self.require_mutable()?;
+        let previous_valid_until = self.valid_until.ok_or(CredentialError::NotExpiring)?;
+        require!(
+            valid_until > previous_valid_until && valid_until > now,
+            CredentialError::InvalidValidityWindow
+        );
+        self.valid_until = Some(valid_until);
+        self.renewal_count = self.renewal_count.saturating_add(1);
+        self.last_renewed_at = Some(now);
+        Ok(previous_valid_until)
+    }
+
+    /// Suspends an active credential or reinstates a suspended one.
+    pub fn set_suspended(&mut self, suspended: bool) -> Result<()> {
+        self.require_mutable()?;
+        self.status = match (self.status, suspended) {
+            (CredentialStatus::Active, true) => CredentialStatus::Suspended,
+            (CredentialStatus::Suspended, false) => CredentialStatus::Active,
+            (_, true) => return err!(CredentialError::CredentialSuspended),
+            (_, false) => return err!(CredentialError::CredentialNotSuspended),
+        };
+        Ok(())
+    }
+
+    /// Marks the credential replaced by the reissued one at `successor`.
+    pub fn supersede(&mut self, successor: Pubkey) -> Result<()> {
+        self.require_mutable()?;
+        self.status = CredentialStatus::Superseded;
+        self.superseded_by = Some(successor);
+        Ok(())
+    }
+
+    /// The tombstone `close_credential` leaves in the credential's place.
+    /// Credentials in a reissue chain stay, so `supersedes` and
+    /// `superseded_by` never dangle.
+    pub fn tombstone(&self, closed_by: Pubkey, now: i64, bump: u8) -> Result<Tombstone> {
+        require!(
+            self.supersedes.is_none() && self.superseded_by.is_none(),
+            CredentialError::CredentialInSupersessionChain
+        );
+        Ok(Tombstone {
+            issuer: self.issuer,
+            hash: self.hash,
+            prior_status: self.status,
+            revocation_reason: self.revocation_reason,
+            closed_by,
+            closed_at: now,
+            bump,
+        })
+    }
+
+    /// Revoked and superseded credentials are final.
+    fn require_mutable(&self) -> Result<()> {
+        match self.status {
+            CredentialStatus::Revoked => err!(CredentialError::CredentialRevoked),
+            CredentialStatus::Superseded => err!(CredentialError::CredentialSuperseded),
+            CredentialStatus::Active | CredentialStatus::Suspended => Ok(()),
+        }
+    }
+
+    /// Classifies the credential's validity window at unix time `now`.
+    /// Revocation is tracked separately in `status`.
+    pub fn validity_at(&self, now: i64) -> Validity {
+        match (self.valid_from, self.valid_until) {
+            (Some(from), _) if now < from => Validity::NotYetValid,
+            (_, Some(until)) if now >= until => Validity::Expired,
+            _ => Validity::Valid,
+        }
+    }
+}
+
+/// Proof that the issuer's own signing key endorsed a credential; the signed
+/// message is `crate::attestation::credential_message`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum IssuerAttestation {
+    Ed25519 {
+        signing_key: Pubkey,
+        signature: [u8; 64],
+    },
+    /// Signed by the issuer's Ethereum key over
+    /// `crate::attestation::eth_credential_message`.
+    Secp256k1 {
+        eth_address: [u8; 20],
+        signature: [u8; 64],
+        recovery_id: u8,
+    },
+}
+
+impl IssuerAttestation {
+    /// Ethereum attestations stand in for the issuer authority's signature;
+    /// Ed25519 ones only add to it.
+    pub fn authorizes_issuance(&self) -> bool {
+        matches!(self, IssuerAttestation::Secp256k1 { .. })
+    }
+}
+
+/// Digest algorithm the issuer used to produce `Credential::hash`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum HashAlgorithm {
+    Sha256,
+    Keccak256,
+    Blake3,
+    /// BN254 Poseidon commitment to a salted set of field elements; see
+    /// `crate::poseidon`.
+    Poseidon,
+    /// Root of a salted per-claim Merkle tree; see `crate::claims`.
+    ClaimsRoot,
+}
+
+impl HashAlgorithm {
+    /// Rejects digests no honest issuer could have produced with this algorithm.
+    pub fn validate_digest(&self, digest: &[u8; 32]) -> Result<()> {
+        require!(digest.iter().any(|b| *b != 0), CredentialError::InvalidHash);
+        if *self == HashAlgorithm::Poseidon {
+            require!(
+                digest[..] < BN254_SCALAR_MODULUS[..],
+                CredentialError::InvalidFieldElement
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Validity {
+    NotYetValid,
+    Valid,
+    Expired,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum CredentialStatus {
+    Active,
+    /// Temporarily invalid (e.g. under investigation); the issuer can reinstate it.
+    Suspended,
+    /// Replaced by a corrected credential; see `Credential::superseded_by`.
+    Superseded,
+    Revoked,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum RevocationReason {
+    IssuedInError,
+    Fraud,
+    Superseded,
+    AcademicMisconduct,
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::error::CredentialError;
+
+/// A staff key allowed to act for an `Issuer` within `scope`, so the
+/// institution's root authority never has to be shared.
+#[account]
+#[derive(InitSpace)]
+pub struct IssuerDelegate {
+    pub issuer: Pubkey,
+    pub delegate: Pubkey,
+    pub scope: DelegateScope,
+    /// Credentials issued by this delegate so far, checked against
+    /// `scope.max_issuance`.
+    pub issued_count: u32,
+    pub created_at: i64,
+    pub created_slot: u64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct DelegateScope {
+    pub can_issue: bool,
+    pub can_revoke: bool,
+    /// Lifetime cap on issuance; `None` means unlimited.
+    pub max_issuance: Option<u32>,
+    /// Unix time after which the delegate can no longer act.
+    pub expires_at: Option<i64>,
+    /// Only credentials of this `CredentialSchema` may be issued; `None`
+    /// allows any schema available to the issuer.
+    pub schema: Option<Pubkey>,
+}
+
+/// What a signer is trying to do on an issuer's behalf.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DelegateAction {
+    Issue { schema: Pubkey },
+    Revoke,
+}
+
+impl IssuerDelegate {
+    /// Fails if `scope` has already expired at `clock`'s unix time.
+    pub fn new(
+        issuer: Pubkey,
+        delegate: Pubkey,
+        scope: DelegateScope,
+        clock: &Clock,
+        bump: u8,
+    ) -> Result<Self> {
+        if let Some(expires_at) = scope.expires_at {
+            require!(
+                expires_at > clock.unix_timestamp,
+                CredentialError::DelegateExpired
+            );
+        }
+        Ok(Self {
+            issuer,
+            delegate,
+            scope,
+            issued_count: 0,
+            created_at: clock.unix_timestamp,
+            created_slot: clock.slot,
+            bump,
+        })
+    }
+
+    /// Fails unless this delegate may perform `action` at unix time `now`.
+    pub fn authorize(&self, action: DelegateAction, now: i64) -> Result<()> {
+        let scope = &self.scope;
+        if let Some(expires_at) = scope.expires_at {
+            require!(now < expires_at, CredentialError::DelegateExpired);
+        }
+        match action {
+            DelegateAction::Issue { schema } => {
+                require!(scope.can_issue, CredentialError::UnauthorizedDelegate);
+                if let Some(allowed) = scope.schema {
+                    require_keys_eq!(allowed, schema, CredentialError::DelegateSchemaNotAllowed);
+                }
+                if let Some(max) = scope.max_issuance {
+                    require!(
+                        self.issued_count < max,
+                        CredentialError::DelegateQuotaExceeded
+                    );
+                }
+            }
+            DelegateAction::Revoke => {
+                require!(scope.can_revoke, CredentialError::UnauthorizedDelegate);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    MAX_AUTHORITY_HISTORY, MAX_ISSUER_DID_LEN, MAX_ISSUER_NAME_LEN, MAX_ISSUER_WEBSITE_LEN,
+};
+use crate::error::CredentialError;
+use crate::state::{DelegateAction, IssuerAttestation, IssuerDelegate};
+
+/// An institution accredited by the program admin to issue credentials.
+#[account]
+#[derive(InitSpace)]
+pub struct Issuer {
+    /// Root key of the institution; signs issuance and manages delegates.
+    pub authority: Pubkey,
+    #[max_len(MAX_ISSUER_NAME_LEN)]
+    pub name: String,
+    #[max_len(MAX_ISSUER_WEBSITE_LEN)]
+    pub website: String,
+    #[max_len(MAX_ISSUER_DID_LEN)]
+    pub did: String,
+    pub status: IssuerStatus,
+    /// Institutional Ed25519 key that must sign every new credential; see
+    /// `crate::attestation`. Batches carry no attestation, so they cannot be
+    /// anchored while it is set. `None` disables the requirement.
+    pub signing_key: Option<Pubkey>,
+    /// Ethereum address whose secp256k1 signature can issue on the issuer's
+    /// behalf through any relayer, for institutions without a Solana key.
+    pub eth_address: Option<[u8; 20]>,
+    pub registered_at: i64,
+    /// Slot from which `authority` has been the root key. Delegates appointed
+    /// at or before it answered to a retired key and can no longer act.
+    pub authority_since_slot: u64,
+    /// Previous root keys, oldest first. Credentials they issued stay valid,
+    /// but they can no longer sign for the issuer. Only the latest
+    /// `MAX_AUTHORITY_HISTORY` are kept.
+    #[max_len(MAX_AUTHORITY_HISTORY)]
+    pub authority_history: Vec<AuthorityRecord>,
+    /// `IssuerMultisig` governing the issuer while it holds `authority`.
+    pub multisig: Option<Pubkey>,
+    /// Slot at which a multisig last took over, or 0. Delegates appointed at
+    /// or before it answered to a single key and can no longer act.
+    pub multisig_since_slot: u64,
+    pub bump: u8,
+}
+
+/// A retired root key and the slots during which it was in effect.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct AuthorityRecord {
+    pub authority: Pubkey,
+    pub effective_from_slot: u64,
+    /// First slot at which this key was no longer the authority.
+    pub effective_until_slot: u64,
+}
+
+impl Issuer {
+    /// Retires the current root key in favour of `new_authority` from `slot`
+    /// onwards, voiding every delegate appointed so far, and returns the
+    /// retired key. A full history drops its oldest record.
+    pub fn set_authority(&mut self, new_authority: Pubkey, slot: u64) -> Result<Pubkey> {
+        require_keys_neq!(
+            new_authority,
+            self.authority,
+            CredentialError::SameAuthority
+        );
+        if self.authority_history.len() == MAX_AUTHORITY_HISTORY {
+            self.authority_history.remove(0);
+        }
+        let previous = self.authority;
+        self.authority_history.push(AuthorityRecord {
+            authority: previous,
+            effective_from_slot: self.authority_since_slot,
+            effective_until_slot: slot,
+        });
+        self.authority = new_authority;
+        self.authority_since_slot = slot;
+        self.multisig = None;
+        Ok(previous)
+    }
+
+    /// Root key in effect at `slot`, e.g. a credential's `issued_slot`, or
+    /// `None` if its record has been dropped from `authority_history`.
+    pub fn authority_at(&self, slot: u64) -> Option<Pubkey> {
+        if slot >= self.authority_since_slot {
+            return Some(self.authority);
+        }
+        self.authority_history
+            .iter()
+            .find(|record| {
+                (record.effective_from_slot..record.effective_until_slot).contains(&slot)
+            })
+            .map(|record| record.authority)
+    }
+
+    /// Hands the issuer to `multisig` from `slot` onwards, voiding every
+    /// delegate appointed so far.
+    pub fn set_multisig(&mut self, multisig: Pubkey, slot: u64) -> Result<Pubkey> {
+        let previous = self.set_authority(multisig, slot)?;
+        self.multisig = Some(multisig);
+        self.multisig_since_slot = slot;
+        Ok(previous)
+    }
+
+    /// Authorizes issuing a credential under `schema`. A secp256k1
+    /// `attestation` from the issuer's Ethereum key stands in for the
+    /// authority, unless a multisig governs the issuer; otherwise `signer`
+    /// must pass `authorize`.
+    pub fn authorize_issuance(
+        &self,
+        signer: &Pubkey,
+        delegate: Option<&IssuerDelegate>,
+        attestation: Option<&IssuerAttestation>,
+        schema: Pubkey,
+        now: i64,
+    ) -> Result<()> {
+        if attestation.is_some_and(IssuerAttestation::authorizes_issuance) {
+            // A relayed issuance answers to the Ethereum key alone.
+            require!(
+                self.multisig.is_none(),
+                CredentialError::IssuerMultisigGoverned
+            );
+            require!(delegate.is_none(), CredentialError::UnauthorizedDelegate);
+            return Ok(());
+        }
+        self.authorize(signer, delegate, DelegateAction::Issue { schema }, now)
+    }
+
+    /// Fails unless `signer` is this issuer's authority or, when `delegate` is
+    /// given, the delegate it names acting within its scope. The delegate
+    /// account's address is pinned by seeds in each instruction.
+    pub fn authorize(
+        &self,
+        signer: &Pubkey,
+        delegate: Option<&IssuerDelegate>,
+        action: DelegateAction,
+        now: i64,
+    ) -> Result<()> {
+        match delegate {
+            Some(delegate) => {
+                require_keys_eq!(
+                    delegate.delegate,
+                    *signer,
+                    CredentialError::UnauthorizedDelegate
+                );
+                require!(
+                    delegate.created_slot > self.multisig_since_slot,
+                    CredentialError::DelegatePredatesMultisig
+                );
+                require!(
+                    delegate.created_slot > self.authority_since_slot,
+                    CredentialError::DelegatePredatesAuthority
+                );
+                delegate.authorize(action, now)
+            }
+            None => {
+                require_keys_eq!(self.authority, *signer, CredentialError::UnregisteredIssuer);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum IssuerStatus {
+    Active,
+    Suspended,
+    Revoked,
+}
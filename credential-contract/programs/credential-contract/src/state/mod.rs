@@ -0,0 +1,23 @@
+pub mod batch;
+pub mod config;
+pub mod credential;
+pub mod delegate;
+pub mod issuer;
+pub mod multisig;
+pub mod predicate;
+pub mod revocation_list;
+pub mod schema;
+pub mod tombstone;
+pub mod verification;
+
+pub use batch::*;
+pub use config::*;
+pub use credential::*;
+pub use delegate::*;
+pub use issuer::*;
+pub use multisig::*;
+pub use predicate::*;
+pub use revocation_list::*;
+pub use schema::*;
+pub use tombstone::*;
+pub use verification::*;
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    MAX_MULTISIG_SIGNERS, MAX_PROPOSAL_REVOCATION_INDICES, MAX_SCHEMA_ID_LEN, MAX_SCHEMA_NAME_LEN,
+    MAX_SCHEMA_URI_LEN,
+};
+use crate::error::CredentialError;
+use crate::state::{CredentialMetadata, DelegateScope, HashAlgorithm, RevocationReason};
+
+/// M-of-N signer set governing an `Issuer`. While the issuer's authority is
+/// this account, no single key can act for the issuer: delegates appointed
+/// before the takeover are void, relayed Ethereum issuance is refused, and
+/// every action goes through `propose_action` / `approve_action` /
+/// `execute_action`. Only the program admin can rotate the issuer's authority
+/// away from the multisig, which dissolves it.
+#[account]
+#[derive(InitSpace)]
+pub struct IssuerMultisig {
+    pub issuer: Pubkey,
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub signers: Vec<Pubkey>,
+    /// Approvals an action needs before it can be executed.
+    pub threshold: u8,
+    /// Index the next proposal will use; part of its PDA seeds.
+    pub proposal_count: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl IssuerMultisig {
+    /// Fails unless `signers` is a non-empty set of distinct keys that
+    /// `threshold` of them can satisfy.
+    pub fn validate_config(signers: &[Pubkey], threshold: u8) -> Result<()> {
+        let mut sorted = signers.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        require!(
+            !signers.is_empty()
+                && signers.len() <= MAX_MULTISIG_SIGNERS
+                && sorted.len() == signers.len()
+                && threshold >= 1
+                && usize::from(threshold) <= signers.len(),
+            CredentialError::InvalidMultisigConfig
+        );
+        Ok(())
+    }
+
+    pub fn require_signer(&self, key: &Pubkey) -> Result<()> {
+        require!(
+            self.signers.contains(key),
+            CredentialError::NotMultisigSigner
+        );
+        Ok(())
+    }
+
+    /// Approvals of `proposal` that still count: those of current signers.
+    /// Approvals by keys removed with `ChangeSigners` lapse.
+    pub fn approvals_of(&self, proposal: &Proposal) -> usize {
+        proposal
+            .approvals
+            .iter()
+            .filter(|approver| self.signers.contains(approver))
+            .count()
+    }
+}
+
+/// An action awaiting approval by an issuer's multisig. Closed back to the
+/// proposer once executed or cancelled.
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub multisig: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: MultisigAction,
+    /// Signers that have approved so far, starting with the proposer. Each
+    /// approval drops those no longer in the signer set.
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub approvals: Vec<Pubkey>,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+/// Issuer operations a multisig can authorize. Arguments mirror the
+/// single-signer instructions of the same name; credentials are named by
+/// hash and batches by root.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub enum MultisigAction {
+    IssueCredential {
+        schema: Pubkey,
+        hash: [u8; 32],
+        algorithm: HashAlgorithm,
+        subject: Option<Pubkey>,
+        valid_from: Option<i64>,
+        valid_until: Option<i64>,
+        metadata: Option<CredentialMetadata>,
+    },
+    StoreCredentialBatch {
+        root: [u8; 32],
+        leaf_count: u32,
+        algorithm: HashAlgorithm,
+    },
+    RevokeCredential {
+        hash: [u8; 32],
+        reason: RevocationReason,
+    },
+    /// `reissue_credential` of the credential at `previous`.
+    ReissueCredential {
+        previous: [u8; 32],
+        schema: Pubkey,
+        hash: [u8; 32],
+        algorithm: HashAlgorithm,
+        subject: Option<Pubkey>,
+        valid_from: Option<i64>,
+        valid_until: Option<i64>,
+        metadata: Option<CredentialMetadata>,
+    },
+    RenewCredential {
+        hash: [u8; 32],
+        valid_until: i64,
+    },
+    SetCredentialSuspended {
+        hash: [u8; 32],
+        suspended: bool,
+    },
+    SetRevocationBits {
+        root: [u8; 32],
+        #[max_len(MAX_PROPOSAL_REVOCATION_INDICES)]
+        indices: Vec<u32>,
+    },
+    CloseCredential {
+        hash: [u8; 32],
+    },
+    /// `register_schema` of a schema owned by the issuer.
+    RegisterSchema {
+        #[max_len(MAX_SCHEMA_ID_LEN)]
+        schema_id: String,
+        version: u16,
+        #[max_len(MAX_SCHEMA_NAME_LEN)]
+        name: String,
+        content_hash: [u8; 32],
+        #[max_len(MAX_SCHEMA_URI_LEN)]
+        uri: String,
+    },
+    /// `set_issuer_signing_key`.
+    SetSigningKey {
+        signing_key: Option<Pubkey>,
+    },
+    AddDelegate {
+        delegate: Pubkey,
+        scope: DelegateScope,
+    },
+    RemoveDelegate {
+        delegate: Pubkey,
+    },
+    /// Replaces the signer set and threshold. Approvals of pending proposals
+    /// by removed signers stop counting.
+    ChangeSigners {
+        #[max_len(MAX_MULTISIG_SIGNERS)]
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    },
+}
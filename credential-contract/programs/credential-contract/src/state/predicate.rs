@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_PREDICATE_ID_LEN, MAX_PREDICATE_INPUTS};
+use crate::groth16::VerifyingKey;
+
+/// A zero-knowledge predicate over credentials of one schema, e.g. "holds a
+/// STEM bachelor's", checked by `verify_zk_predicate`. Lives at
+/// `[PREDICATE_SEED, predicate_id]`.
+///
+/// The circuit's public inputs are the Poseidon commitments of
+/// `credential_count` stored credentials, the anonymity set the holder hides
+/// among, then the fixed `parameters` that define the predicate, then a
+/// single challenge. The parameters are set at registration so a prover
+/// cannot weaken the predicate, e.g. by lowering a minimum to zero.
+#[account]
+#[derive(InitSpace)]
+pub struct PredicateVerifier {
+    #[max_len(MAX_PREDICATE_ID_LEN)]
+    pub predicate_id: String,
+    /// Schema every credential in the anonymity set must use; it defines what
+    /// the commitments hold.
+    pub schema: Pubkey,
+    pub credential_count: u8,
+    /// `credential_count + parameters.len() + 1`.
+    pub input_count: u8,
+    #[max_len(MAX_PREDICATE_INPUTS)]
+    pub parameters: Vec<[u8; 32]>,
+    pub key: VerifyingKey,
+    /// Input points of the verifying key, appended by
+    /// `extend_predicate_verifier` until there are `input_count + 1`.
+    #[max_len(MAX_PREDICATE_INPUTS + 1)]
+    pub ic: Vec<[u8; 64]>,
+    pub registered_by: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl PredicateVerifier {
+    /// Whether the whole verifying key has been uploaded.
+    pub fn is_complete(&self) -> bool {
+        self.ic.len() == usize::from(self.input_count) + 1
+    }
+}
+
+/// Marks a challenge as used for one predicate, so each proof verifies
+/// once. Lives at `[PREDICATE_NULLIFIER_SEED, verifier, challenge]`.
+#[account]
+#[derive(InitSpace)]
+pub struct PredicateNullifier {
+    pub verifier: Pubkey,
+    pub challenge: [u8; 32],
+    pub consumed_by: Pubkey,
+    pub consumed_at: i64,
+    pub bump: u8,
+}
+
+/// Borsh-encoded into the transaction return data by `verify_zk_predicate`.
+/// A relying party checks that `challenge` is the one it issued.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PredicateVerification {
+    pub verifier: Pubkey,
+    pub schema: Pubkey,
+    pub parameters: Vec<[u8; 32]>,
+    pub challenge: [u8; 32],
+    /// The anonymity set, one of which the holder opened.
+    pub credentials: Vec<Pubkey>,
+    pub verified_at: i64,
+}
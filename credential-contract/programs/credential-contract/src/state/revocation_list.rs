@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::error::CredentialError;
+
+/// Revocation status of every leaf in a `CredentialBatch`, one bit per leaf
+/// position (bit `i % 8` of byte `i / 8`), after W3C StatusList2021. Bits are
+/// only ever set: like `revoke_credential`, batch revocation is final.
+#[account]
+pub struct RevocationList {
+    pub batch: Pubkey,
+    pub issuer: Pubkey,
+    pub bits: Vec<u8>,
+    pub revoked_count: u32,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl RevocationList {
+    /// An empty list for `batch` of `leaf_count` leaves.
+    pub fn new(batch: Pubkey, issuer: Pubkey, leaf_count: u32, now: i64, bump: u8) -> Self {
+        Self {
+            batch,
+            issuer,
+            bits: vec![0; Self::bitmap_len(leaf_count)],
+            revoked_count: 0,
+            updated_at: now,
+            bump,
+        }
+    }
+
+    /// Account size, discriminator included, for a batch of `leaf_count` leaves.
+    pub fn space(leaf_count: u32) -> usize {
+        8 + 32 + 32 + 4 + Self::bitmap_len(leaf_count) + 4 + 8 + 1
+    }
+
+    pub fn bitmap_len(leaf_count: u32) -> usize {
+        (leaf_count as usize).div_ceil(8)
+    }
+
+    pub fn is_revoked(&self, index: u32) -> bool {
+        let index = index as usize;
+        self.bits
+            .get(index / 8)
+            .is_some_and(|byte| byte & (1 << (index % 8)) != 0)
+    }
+
+    /// Revokes every position in `indices`, each below `leaf_count`. Positions
+    /// that are already revoked are skipped, so retrying a partially landed
+    /// update is harmless.
+    pub fn revoke_all(&mut self, indices: &[u32], leaf_count: u32, now: i64) -> Result<()> {
+        require!(
+            !indices.is_empty() && indices.iter().all(|index| *index < leaf_count),
+            CredentialError::InvalidLeafIndex
+        );
+        let mut newly_revoked = 0u32;
+        for index in indices {
+            if self.revoke(*index) {
+                newly_revoked += 1;
+            }
+        }
+        self.revoked_count += newly_revoked;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Sets the bit for `index`, returning whether it was newly set.
+    pub fn revoke(&mut self, index: u32) -> bool {
+        let index = index as usize;
+        let mask = 1 << (index % 8);
+        let byte = &mut self.bits[index / 8];
+        let newly_revoked = *byte & mask == 0;
+        *byte |= mask;
+        newly_revoked
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_SCHEMA_ID_LEN, MAX_SCHEMA_NAME_LEN, MAX_SCHEMA_URI_LEN};
+use crate::error::CredentialError;
+
+/// Describes what a credential hash commits to: the JSON schema of the
+/// document the issuer hashed. Each version is its own immutable account at
+/// `[SCHEMA_SEED, registry, schema_id, version as u16 LE]`.
+#[account]
+#[derive(InitSpace)]
+pub struct CredentialSchema {
+    /// `Issuer` account that owns the schema, or the `Config` account for
+    /// program-wide schemas registered by the admin.
+    pub registry: Pubkey,
+    /// Stable identifier shared by every version, e.g. `bachelor-degree`.
+    #[max_len(MAX_SCHEMA_ID_LEN)]
+    pub schema_id: String,
+    pub version: u16,
+    #[max_len(MAX_SCHEMA_NAME_LEN)]
+    pub name: String,
+    /// SHA-256 of the JSON-schema document found at `uri`.
+    pub content_hash: [u8; 32],
+    #[max_len(MAX_SCHEMA_URI_LEN)]
+    pub uri: String,
+    pub registered_by: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl CredentialSchema {
+    /// Fails unless `schema_id` is 1-32 bytes, `name` and `uri` are within
+    /// their maximum lengths and `content_hash` is non-zero.
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.schema_id.is_empty()
+                && self.schema_id.len() <= MAX_SCHEMA_ID_LEN
+                && self.name.len() <= MAX_SCHEMA_NAME_LEN
+                && self.uri.len() <= MAX_SCHEMA_URI_LEN,
+            CredentialError::SchemaFieldTooLong
+        );
+        require!(
+            self.content_hash.iter().any(|b| *b != 0),
+            CredentialError::InvalidHash
+        );
+        Ok(())
+    }
+
+    /// Whether credentials of `issuer` may reference this schema: its own
+    /// schemas and the program-wide ones registered under `config`.
+    pub fn is_available_to(&self, issuer: &Pubkey, config: &Pubkey) -> bool {
+        self.registry == *issuer || self.registry == *config
+    }
+}
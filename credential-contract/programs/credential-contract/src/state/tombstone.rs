@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{CredentialStatus, RevocationReason};
+
+/// Left behind by `close_credential` so a closed credential's hash cannot be
+/// silently issued again under the same issuer, and so `verify_credential`
+/// can report it as `Closed` rather than never issued.
+#[account]
+#[derive(InitSpace)]
+pub struct Tombstone {
+    pub issuer: Pubkey,
+    pub hash: [u8; 32],
+    /// Status of the credential when it was closed.
+    pub prior_status: CredentialStatus,
+    pub revocation_reason: Option<RevocationReason>,
+    pub closed_by: Pubkey,
+    pub closed_at: i64,
+    pub bump: u8,
+}
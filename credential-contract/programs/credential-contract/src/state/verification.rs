@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+
+use crate::error::CredentialError;
+use crate::state::{
+    Credential, CredentialBatch, CredentialMetadata, CredentialStatus, HashAlgorithm, Issuer,
+    IssuerStatus, RevocationReason, Tombstone, Validity,
+};
+
+/// Outcome of `verify_credential`, most severe first: a revoked credential
+/// reports `Revoked` even if it has also expired.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VerificationStatus {
+    Valid,
+    NotFound,
+    /// Deleted by `close_credential`; `revocation_reason` carries the reason
+    /// if it had been revoked first.
+    Closed,
+    Revoked,
+    Suspended,
+    /// Replaced by a reissued credential; follow `superseded_by`.
+    Superseded,
+    IssuerInactive,
+    NotYetValid,
+    Expired,
+}
+
+/// Borsh-encoded into the transaction return data by `verify_credential`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct VerificationResult {
+    pub status: VerificationStatus,
+    pub credential: Pubkey,
+    pub issuer: Pubkey,
+    pub issuer_status: IssuerStatus,
+    /// `CredentialSchema` the credential was issued under; `None` for batches.
+    pub schema: Option<Pubkey>,
+    pub subject: Option<Pubkey>,
+    pub algorithm: Option<HashAlgorithm>,
+    pub metadata: Option<CredentialMetadata>,
+    pub issued_at: Option<i64>,
+    /// Issuer root key in effect at issuance, which signed or appointed
+    /// whoever did; `None` if it has aged out of `Issuer::authority_history`.
+    pub issuing_authority: Option<Pubkey>,
+    pub valid_from: Option<i64>,
+    pub valid_until: Option<i64>,
+    pub revoked_at: Option<i64>,
+    pub revocation_reason: Option<RevocationReason>,
+    pub superseded_by: Option<Pubkey>,
+    /// Unix time the check was evaluated against.
+    pub checked_at: i64,
+}
+
+impl VerificationResult {
+    pub fn not_found(
+        credential: Pubkey,
+        issuer: Pubkey,
+        issuer_status: IssuerStatus,
+        now: i64,
+    ) -> Self {
+        Self {
+            status: VerificationStatus::NotFound,
+            credential,
+            issuer,
+            issuer_status,
+            schema: None,
+            subject: None,
+            algorithm: None,
+            metadata: None,
+            issued_at: None,
+            issuing_authority: None,
+            valid_from: None,
+            valid_until: None,
+            revoked_at: None,
+            revocation_reason: None,
+            superseded_by: None,
+            checked_at: now,
+        }
+    }
+
+    /// Result for a credential that `close_credential` replaced with
+    /// `tombstone`.
+    pub fn closed(
+        credential: Pubkey,
+        tombstone: &Tombstone,
+        issuer_status: IssuerStatus,
+        now: i64,
+    ) -> Self {
+        Self {
+            status: VerificationStatus::Closed,
+            revocation_reason: tombstone.revocation_reason,
+            ..Self::not_found(credential, tombstone.issuer, issuer_status, now)
+        }
+    }
+
+    pub fn for_credential(
+        address: Pubkey,
+        credential: &Credential,
+        issuer: &Issuer,
+        now: i64,
+    ) -> Self {
+        let issuer_status = issuer.status;
+        let status = match (
+            credential.status,
+            issuer_status,
+            credential.validity_at(now),
+        ) {
+            (CredentialStatus::Revoked, _, _) => VerificationStatus::Revoked,
+            (CredentialStatus::Suspended, _, _) => VerificationStatus::Suspended,
+            (CredentialStatus::Superseded, _, _) => VerificationStatus::Superseded,
+            (_, IssuerStatus::Suspended | IssuerStatus::Revoked, _) => {
+                VerificationStatus::IssuerInactive
+            }
+            (_, _, Validity::NotYetValid) => VerificationStatus::NotYetValid,
+            (_, _, Validity::Expired) => VerificationStatus::Expired,
+            (_, _, Validity::Valid) => VerificationStatus::Valid,
+        };
+
+        Self {
+            status,
+            credential: address,
+            issuer: credential.issuer,
+            issuer_status,
+            schema: Some(credential.schema),
+            subject: credential.subject,
+            algorithm: Some(credential.algorithm),
+            metadata: credential.metadata.clone(),
+            issued_at: Some(credential.issued_at),
+            issuing_authority: issuer.authority_at(credential.issued_slot),
+            valid_from: credential.valid_from,
+            valid_until: credential.valid_until,
+            revoked_at: credential.revoked_at,
+            revocation_reason: credential.revocation_reason,
+            superseded_by: credential.superseded_by,
+            checked_at: now,
+        }
+    }
+
+    /// Result for a leaf of a `CredentialBatch`; `credential` holds the batch
+    /// address, `included` says whether the Merkle proof reached the root and
+    /// `revoked` whether the leaf's bit is set in the batch revocation list.
+    pub fn for_batch(
+        address: Pubkey,
+        batch: &CredentialBatch,
+        included: bool,
+        revoked: bool,
+        issuer: &Issuer,
+        now: i64,
+    ) -> Self {
+        let issuer_status = issuer.status;
+        let status = match (included, revoked, issuer_status) {
+            (false, _, _) => VerificationStatus::NotFound,
+            (true, true, _) => VerificationStatus::Revoked,
+            (true, false, IssuerStatus::Suspended | IssuerStatus::Revoked) => {
+                VerificationStatus::IssuerInactive
+            }
+            (true, false, IssuerStatus::Active) => VerificationStatus::Valid,
+        };
+
+        Self {
+            status,
+            credential: address,
+            issuer: batch.issuer,
+            issuer_status,
+            schema: None,
+            subject: None,
+            algorithm: Some(batch.algorithm),
+            metadata: None,
+            issued_at: Some(batch.issued_at),
+            issuing_authority: issuer.authority_at(batch.issued_slot),
+            valid_from: None,
+            valid_until: None,
+            revoked_at: None,
+            revocation_reason: None,
+            superseded_by: None,
+            checked_at: now,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.status == VerificationStatus::Valid
+    }
+
+    /// Maps any non-`Valid` status onto the matching `CredentialError`.
+    pub fn require_valid(&self) -> Result<()> {
+        match self.status {
+            VerificationStatus::Valid => Ok(()),
+            VerificationStatus::NotFound => err!(CredentialError::CredentialNotFound),
+            VerificationStatus::Closed => err!(CredentialError::CredentialClosed),
+            VerificationStatus::Revoked => err!(CredentialError::CredentialRevoked),
+            VerificationStatus::Suspended => err!(CredentialError::CredentialSuspended),
+            VerificationStatus::Superseded => err!(CredentialError::CredentialSuperseded),
+            VerificationStatus::IssuerInactive => err!(CredentialError::IssuerNotActive),
+            VerificationStatus::NotYetValid => err!(CredentialError::CredentialNotYetValid),
+            VerificationStatus::Expired => err!(CredentialError::CredentialExpired),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+// The `#[program]` expansion references cfgs and `AccountInfo::realloc`, which
+// newer toolchains flag; neither is actionable from this crate.
+#![allow(unexpected_cfgs, deprecated)]
+
+//! Example consumer of `credential_contract::gate`: a membership list that
+//! only admits wallets holding a valid credential of one type from one
+//! accredited issuer.
+
+use anchor_lang::prelude::*;
+use credential_contract::program::CredentialContract;
+use credential_contract::{CredentialSchema, Issuer};
+
+declare_id!("G6ZSkkc9EwF6UgY17yyzEGu7JkyVNh2bWhoBV6vAikV8");
+
+pub const GATE_SEED: &[u8] = b"gate";
+pub const MEMBERSHIP_SEED: &[u8] = b"membership";
+
+#[program]
+pub mod credential_gate {
+    use super::*;
+
+    pub fn create_gate(ctx: Context<CreateGate>) -> Result<()> {
+        let gate = &mut ctx.accounts.gate;
+        gate.authority = ctx.accounts.authority.key();
+        gate.issuer = ctx.accounts.issuer.key();
+        gate.schema = ctx.accounts.schema.key();
+        gate.bump = ctx.bumps.gate;
+        Ok(())
+    }
+
+    pub fn join(ctx: Context<Join>, hash: [u8; 32]) -> Result<()> {
+        credential_contract::gate::require_valid_credential(
+            ctx.accounts.credential_program.to_account_info(),
+            ctx.accounts.issuer.to_account_info(),
+            ctx.accounts.credential.to_account_info(),
+            ctx.accounts.tombstone.to_account_info(),
+            hash,
+            Some(ctx.accounts.member.key()),
+            Some(ctx.accounts.gate.schema),
+        )?;
+
+        let membership = &mut ctx.accounts.membership;
+        membership.gate = ctx.accounts.gate.key();
+        membership.member = ctx.accounts.member.key();
+        membership.credential = ctx.accounts.credential.key();
+        membership.joined_at = Clock::get()?.unix_timestamp;
+        membership.bump = ctx.bumps.membership;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct CreateGate<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Gate::INIT_SPACE,
+        seeds = [GATE_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub gate: Account<'info, Gate>,
+    pub issuer: Account<'info, Issuer>,
+    pub schema: Account<'info, CredentialSchema>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Join<'info> {
+    #[account(has_one = issuer)]
+    pub gate: Account<'info, Gate>,
+    pub issuer: Account<'info, Issuer>,
+    /// CHECK: address and contents are checked by `verify_credential`.
+    pub credential: UncheckedAccount<'info>,
+    /// CHECK: address and contents are checked by `verify_credential`.
+    pub tombstone: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = member,
+        space = 8 + Membership::INIT_SPACE,
+        seeds = [MEMBERSHIP_SEED, gate.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub membership: Account<'info, Membership>,
+    #[account(mut)]
+    pub member: Signer<'info>,
+    pub credential_program: Program<'info, CredentialContract>,
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Gate {
+    pub authority: Pubkey,
+    /// Only credentials from this `Issuer` account are accepted.
+    pub issuer: Pubkey,
+    /// ...and only those issued under this `CredentialSchema`.
+    pub schema: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Membership {
+    pub gate: Pubkey,
+    pub member: Pubkey,
+    pub credential: Pubkey,
+    pub joined_at: i64,
+    pub bump: u8,
+}
@@ -1,16 +1,1577 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
+import {
+  ComputeBudgetProgram,
+  Ed25519Program,
+  PublicKey,
+  Secp256k1Program,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
+import { createHash, randomBytes } from "crypto";
+import { expect } from "chai";
+import { readFileSync } from "fs";
 import { CredentialContract } from "../target/types/credential_contract";
 
 describe("credential-contract", () => {
   // Configure the client to use the local cluster.
-  anchor.setProvider(anchor.AnchorProvider.env());
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
 
   const program = anchor.workspace.credentialContract as Program<CredentialContract>;
+  const admin = provider.wallet.publicKey;
+  const authority = provider.wallet.publicKey;
+
+  const [config] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [issuer] = PublicKey.findProgramAddressSync(
+    [Buffer.from("issuer"), authority.toBuffer()],
+    program.programId
+  );
+
+  const schemaVersion = Buffer.alloc(2);
+  schemaVersion.writeUInt16LE(1);
+  const [schema] = PublicKey.findProgramAddressSync(
+    [Buffer.from("schema"), config.toBuffer(), Buffer.from("bachelor-degree"), schemaVersion],
+    program.programId
+  );
+
+  const digest = (document: string) =>
+    Array.from(createHash("sha256").update(document).digest());
+
+  const credentialAddress = (hash: number[]) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("credential"), issuer.toBuffer(), Buffer.from(hash)],
+      program.programId
+    )[0];
+
+  const issue = (hash: number[], validUntil: anchor.BN | null = null) =>
+    program.methods
+      .storeCredential(hash, { sha256: {} }, null, null, validUntil, null)
+      .accountsPartial({
+        credential: credentialAddress(hash),
+        issuer,
+        schema,
+        authority,
+        delegate: null,
+      })
+      .rpc();
+
+  // Secp256k1 precompile instruction in which an Ethereum key, fresh unless
+  // given, signs a relayed issuance of `hash` by `issuerKey` with default
+  // terms, or its reissue in place of the credential at `supersedes`.
+  const ethIssuance = (
+    issuerKey: PublicKey,
+    hash: number[],
+    privateKey: Buffer = randomBytes(32),
+    supersedes?: PublicKey
+  ) => {
+    const message = Buffer.concat([
+      Buffer.from("blockverify:credential:v1"),
+      program.programId.toBuffer(),
+      issuerKey.toBuffer(),
+      schema.toBuffer(),
+      Buffer.from(hash),
+      PublicKey.default.toBuffer(),
+      // Borsh (algorithm: Sha256, valid_from: None, valid_until: None, metadata: None).
+      Buffer.from([0, 0, 0, 0]),
+      supersedes ? supersedes.toBuffer() : Buffer.alloc(0),
+    ]);
+    const ix = Secp256k1Program.createInstructionWithPrivateKey({
+      privateKey,
+      message: Buffer.concat([
+        Buffer.from(`\x19Ethereum Signed Message:\n${message.length}`),
+        message,
+      ]),
+    });
+    return { ix, ethAddress: Array.from(ix.data.subarray(12, 32)) };
+  };
 
   it("Is initialized!", async () => {
-    // Add your test here.
-    const tx = await program.methods.initialize().rpc();
-    console.log("Your transaction signature", tx);
+    const [programData] = PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+    );
+    await program.methods.initialize().accountsPartial({ admin, programData }).rpc();
+
+    const account = await program.account.config.fetch(config);
+    expect(account.admin.toBase58()).to.equal(admin.toBase58());
+    expect(account.paused).to.be.false;
+    expect(account.version).to.equal(1);
+  });
+
+  it("registers an accredited issuer", async () => {
+    await program.methods
+      .registerIssuer(authority, "Example University", "https://example.edu", "did:web:example.edu")
+      .accounts({ admin })
+      .rpc();
+
+    const account = await program.account.issuer.fetch(issuer);
+    expect(account.name).to.equal("Example University");
+    expect(account.status).to.deep.equal({ active: {} });
+  });
+
+  it("registers a program-wide credential schema", async () => {
+    const contentHash = digest('{"$id":"bachelor-degree","version":1}');
+    await program.methods
+      .registerSchema(
+        "bachelor-degree",
+        1,
+        "Bachelor's degree",
+        contentHash,
+        "https://schemas.example.edu/bachelor-degree/v1.json"
+      )
+      .accountsPartial({ schema, issuer: null, authority: admin })
+      .rpc();
+
+    const account = await program.account.credentialSchema.fetch(schema);
+    expect(account.registry.toBase58()).to.equal(config.toBase58());
+    expect(account.version).to.equal(1);
+    expect(account.contentHash).to.deep.equal(contentHash);
+  });
+
+  it("stores a credential at its issuer/hash PDA", async () => {
+    const hash = digest("bachelor-of-science-2025-0001");
+    const credential = credentialAddress(hash);
+    const subject = anchor.web3.Keypair.generate().publicKey;
+
+    await program.methods
+      .storeCredential(hash, { sha256: {} }, subject, null, null, null)
+      .accountsPartial({ credential, issuer, schema, authority, delegate: null })
+      .rpc();
+
+    const account = await program.account.credential.fetch(credential);
+    expect(account.schema.toBase58()).to.equal(schema.toBase58());
+    expect(account.hash).to.deep.equal(hash);
+    expect(account.algorithm).to.deep.equal({ sha256: {} });
+    expect(account.issuer.toBase58()).to.equal(issuer.toBase58());
+    expect(account.issuedBy.toBase58()).to.equal(authority.toBase58());
+    expect(account.subject.toBase58()).to.equal(subject.toBase58());
+    expect(account.issuedAt.toNumber()).to.be.greaterThan(0);
+    expect(account.issuedSlot.toNumber()).to.be.greaterThan(0);
+  });
+
+  it("rejects an all-zero digest", async () => {
+    try {
+      await issue(new Array(32).fill(0));
+      expect.fail("zero digest was accepted");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("InvalidHash");
+    }
+  });
+
+  it("revokes a credential with a reason code", async () => {
+    const hash = digest("bachelor-of-arts-2025-0002");
+    const credential = credentialAddress(hash);
+
+    await issue(hash);
+    await program.methods
+      .revokeCredential({ issuedInError: {} })
+      .accountsPartial({ credential, issuer, authority, delegate: null })
+      .rpc();
+
+    const account = await program.account.credential.fetch(credential);
+    expect(account.status).to.deep.equal({ revoked: {} });
+    expect(account.revocationReason).to.deep.equal({ issuedInError: {} });
+    expect(account.revokedAt).to.not.be.null;
+  });
+
+  it("renews an expiring credential", async () => {
+    const hash = digest("first-aid-certificate-2025-0003");
+    const credential = credentialAddress(hash);
+    const now = Math.floor(Date.now() / 1000);
+    const renewedUntil = new anchor.BN(now + 7200);
+
+    await issue(hash, new anchor.BN(now + 3600));
+    await program.methods
+      .renewCredential(renewedUntil)
+      .accountsPartial({ credential, issuer, authority })
+      .rpc();
+
+    const account = await program.account.credential.fetch(credential);
+    expect(account.validUntil.toNumber()).to.equal(renewedUntil.toNumber());
+    expect(account.renewalCount).to.equal(1);
+  });
+
+  it("refuses issuance from a suspended issuer", async () => {
+    await program.methods
+      .setIssuerStatus({ suspended: {} })
+      .accountsPartial({ issuer, admin })
+      .rpc();
+
+    try {
+      await issue(digest("master-of-science-2025-0004"));
+      expect.fail("suspended issuer was allowed to issue");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("IssuerNotActive");
+    } finally {
+      await program.methods
+        .setIssuerStatus({ active: {} })
+        .accountsPartial({ issuer, admin })
+        .rpc();
+    }
+  });
+
+  it("blocks issuance while paused", async () => {
+    await program.methods.setPaused(true).accounts({ admin }).rpc();
+
+    try {
+      await issue(digest("master-of-arts-2025-0005"));
+      expect.fail("issued while paused");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("ProgramPaused");
+    } finally {
+      await program.methods.setPaused(false).accounts({ admin }).rpc();
+    }
+  });
+
+  it("transfers the admin role in two steps", async () => {
+    const successor = anchor.web3.Keypair.generate();
+
+    await program.methods.nominateAdmin(successor.publicKey).accounts({ admin }).rpc();
+    await program.methods
+      .acceptAdmin()
+      .accounts({ pendingAdmin: successor.publicKey })
+      .signers([successor])
+      .rpc();
+    let account = await program.account.config.fetch(config);
+    expect(account.admin.toBase58()).to.equal(successor.publicKey.toBase58());
+
+    await program.methods
+      .nominateAdmin(admin)
+      .accounts({ admin: successor.publicKey })
+      .signers([successor])
+      .rpc();
+    await program.methods.acceptAdmin().accounts({ pendingAdmin: admin }).rpc();
+    account = await program.account.config.fetch(config);
+    expect(account.admin.toBase58()).to.equal(admin.toBase58());
+    expect(account.pendingAdmin).to.be.null;
+  });
+
+  it("emits CredentialIssued on issuance", async () => {
+    const hash = digest("diploma-2025-0006");
+    let event: any = null;
+    const listener = program.addEventListener("credentialIssued", (e) => {
+      event = e;
+    });
+
+    await issue(hash);
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    expect(event).to.not.be.null;
+    expect(event.credential.toBase58()).to.equal(credentialAddress(hash).toBase58());
+    expect(event.hash).to.deep.equal(hash);
+  });
+
+  it("suspends and reinstates a credential", async () => {
+    const hash = digest("certificate-2025-0007");
+    const credential = credentialAddress(hash);
+    await issue(hash);
+
+    await program.methods
+      .setCredentialSuspended(true)
+      .accountsPartial({ credential, issuer, authority })
+      .rpc();
+    let account = await program.account.credential.fetch(credential);
+    expect(account.status).to.deep.equal({ suspended: {} });
+
+    await program.methods
+      .setCredentialSuspended(false)
+      .accountsPartial({ credential, issuer, authority })
+      .rpc();
+    account = await program.account.credential.fetch(credential);
+    expect(account.status).to.deep.equal({ active: {} });
+  });
+
+  it("verifies credentials through return data", async () => {
+    const verify = (hash: number[]) =>
+      program.methods
+        .verifyCredential(hash)
+        .accountsPartial({ issuer, credential: credentialAddress(hash) })
+        .view();
+
+    const valid = await verify(digest("bachelor-of-science-2025-0001"));
+    expect(valid.status).to.deep.equal({ valid: {} });
+
+    const revoked = await verify(digest("bachelor-of-arts-2025-0002"));
+    expect(revoked.status).to.deep.equal({ revoked: {} });
+    expect(revoked.revocationReason).to.deep.equal({ issuedInError: {} });
+
+    const missing = await verify(digest("never-issued"));
+    expect(missing.status).to.deep.equal({ notFound: {} });
+  });
+
+  it("anchors a cohort as a Merkle batch and verifies a leaf", async () => {
+    // Mirrors credential_contract::merkle: prefixed SHA-256, odd nodes carried up.
+    const sha = (...parts: Buffer[]) => createHash("sha256").update(Buffer.concat(parts)).digest();
+    const leaves = ["alice", "bob", "carol"].map((name) => Buffer.from(digest(`cohort-2025-${name}`)));
+    const level0 = leaves.map((leaf) => sha(Buffer.from([0]), leaf));
+    const level1 = [sha(Buffer.from([1]), level0[0], level0[1]), level0[2]];
+    const root = Array.from(sha(Buffer.from([1]), level1[0], level1[1]));
+
+    const [batch] = PublicKey.findProgramAddressSync(
+      [Buffer.from("batch"), issuer.toBuffer(), Buffer.from(root)],
+      program.programId
+    );
+    await program.methods
+      .storeCredentialBatch(root, 3, { sha256: {} })
+      .accountsPartial({ batch, issuer, authority })
+      .rpc();
+
+    const verify = (leaf: Buffer, index: number, proof: Buffer[]) =>
+      program.methods
+        .verifyInBatch(Array.from(leaf), index, proof.map((node) => Array.from(node)))
+        .accountsPartial({ issuer, batch })
+        .view();
+
+    const bob = await verify(leaves[1], 1, [level0[0], level1[1]]);
+    expect(bob.status).to.deep.equal({ valid: {} });
+
+    const carol = await verify(leaves[2], 2, [level1[0]]);
+    expect(carol.status).to.deep.equal({ valid: {} });
+
+    const forged = await verify(Buffer.from(digest("cohort-2025-mallory")), 1, [level0[0], level1[1]]);
+    expect(forged.status).to.deep.equal({ notFound: {} });
+
+    await program.methods
+      .setRevocationBits([1])
+      .accountsPartial({ batch, issuer, authority })
+      .rpc();
+    const revokedBob = await verify(leaves[1], 1, [level0[0], level1[1]]);
+    expect(revokedBob.status).to.deep.equal({ revoked: {} });
+    const stillValid = await verify(leaves[2], 2, [level1[0]]);
+    expect(stillValid.status).to.deep.equal({ valid: {} });
+  });
+
+  it("closes a credential and keeps its hash reserved", async () => {
+    const hash = digest("typo-certificate-2025-0008");
+    const credential = credentialAddress(hash);
+    const recipient = anchor.web3.Keypair.generate().publicKey;
+    await issue(hash);
+    await program.methods
+      .revokeCredential({ issuedInError: {} })
+      .accountsPartial({ credential, issuer, authority, delegate: null })
+      .rpc();
+
+    await program.methods
+      .closeCredential()
+      .accountsPartial({ credential, issuer, authority, recipient })
+      .rpc();
+
+    expect(await provider.connection.getAccountInfo(credential)).to.be.null;
+    expect(await provider.connection.getBalance(recipient)).to.be.greaterThan(0);
+
+    const result = await program.methods
+      .verifyCredential(hash)
+      .accountsPartial({ issuer, credential })
+      .view();
+    expect(result.status).to.deep.equal({ closed: {} });
+    expect(result.revocationReason).to.deep.equal({ issuedInError: {} });
+
+    try {
+      await issue(hash);
+      expect.fail("closed hash was re-issued");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("DuplicateCredential");
+    }
+  });
+
+  it("reissues a corrected credential and links the versions", async () => {
+    const original = digest("bachelor-of-engineering-2025-0009");
+    const corrected = digest("bachelor-of-engineering-2025-0009-name-corrected");
+    const previous = credentialAddress(original);
+    const credential = credentialAddress(corrected);
+    await issue(original);
+
+    await program.methods
+      .reissueCredential(corrected, { sha256: {} }, null, null, null, null)
+      .accountsPartial({ previous, credential, issuer, schema, authority, delegate: null })
+      .rpc();
+
+    const old = await program.account.credential.fetch(previous);
+    expect(old.status).to.deep.equal({ superseded: {} });
+    expect(old.supersededBy.toBase58()).to.equal(credential.toBase58());
+
+    const current = await program.account.credential.fetch(credential);
+    expect(current.supersedes.toBase58()).to.equal(previous.toBase58());
+
+    const result = await program.methods
+      .verifyCredential(original)
+      .accountsPartial({ issuer, credential: previous })
+      .view();
+    expect(result.status).to.deep.equal({ superseded: {} });
+    expect(result.supersededBy.toBase58()).to.equal(credential.toBase58());
+
+    for (const linked of [previous, credential]) {
+      try {
+        await program.methods
+          .closeCredential()
+          .accountsPartial({ credential: linked, issuer, authority, recipient: authority })
+          .rpc();
+        expect.fail("closed a credential in a reissue chain");
+      } catch (err) {
+        expect((err as anchor.AnchorError).error.errorCode.code).to.equal(
+          "CredentialInSupersessionChain"
+        );
+      }
+    }
+  });
+
+  it("lets a scoped delegate issue on the issuer's behalf", async () => {
+    const staff = anchor.web3.Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(staff.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+    );
+    const [delegate] = PublicKey.findProgramAddressSync(
+      [Buffer.from("delegate"), issuer.toBuffer(), staff.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .addIssuerDelegate(staff.publicKey, {
+        canIssue: true,
+        canRevoke: false,
+        maxIssuance: 1,
+        expiresAt: null,
+        schema,
+      })
+      .accountsPartial({ issuer, authority })
+      .rpc();
+
+    const issueAsStaff = (hash: number[]) =>
+      program.methods
+        .storeCredential(hash, { sha256: {} }, null, null, null, null)
+        .accountsPartial({
+          credential: credentialAddress(hash),
+          issuer,
+          schema,
+          delegate,
+          authority: staff.publicKey,
+        })
+        .signers([staff])
+        .rpc();
+
+    const hash = digest("registrar-issued-2025-0010");
+    await issueAsStaff(hash);
+    const account = await program.account.credential.fetch(credentialAddress(hash));
+    expect(account.issuedBy.toBase58()).to.equal(staff.publicKey.toBase58());
+
+    try {
+      await issueAsStaff(digest("registrar-issued-2025-0011"));
+      expect.fail("delegate exceeded its issuance limit");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("DelegateQuotaExceeded");
+    }
+
+    try {
+      await program.methods
+        .revokeCredential({ fraud: {} })
+        .accountsPartial({
+          credential: credentialAddress(hash),
+          issuer,
+          delegate,
+          authority: staff.publicKey,
+        })
+        .signers([staff])
+        .rpc();
+      expect.fail("delegate without revoke scope revoked a credential");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("UnauthorizedDelegate");
+    }
+  });
+
+  it("keeps a delegate's reissues within its scope", async () => {
+    const staff = anchor.web3.Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(staff.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+    );
+    const [delegate] = PublicKey.findProgramAddressSync(
+      [Buffer.from("delegate"), issuer.toBuffer(), staff.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .addIssuerDelegate(staff.publicKey, {
+        canIssue: true,
+        canRevoke: false,
+        maxIssuance: null,
+        expiresAt: null,
+        schema,
+      })
+      .accountsPartial({ issuer, authority })
+      .rpc();
+
+    const schemaVersion = Buffer.alloc(2);
+    schemaVersion.writeUInt16LE(1);
+    const [supplement] = PublicKey.findProgramAddressSync(
+      [Buffer.from("schema"), issuer.toBuffer(), Buffer.from("diploma-supplement"), schemaVersion],
+      program.programId
+    );
+    await program.methods
+      .registerSchema(
+        "diploma-supplement",
+        1,
+        "Diploma supplement",
+        digest('{"$id":"diploma-supplement","version":1}'),
+        "https://schemas.example.edu/diploma-supplement/v1.json"
+      )
+      .accountsPartial({ schema: supplement, issuer, authority })
+      .rpc();
+
+    const original = digest("diploma-supplement-2025-0012");
+    await program.methods
+      .storeCredential(original, { sha256: {} }, null, null, null, null)
+      .accountsPartial({
+        credential: credentialAddress(original),
+        issuer,
+        schema: supplement,
+        authority,
+        delegate: null,
+      })
+      .rpc();
+
+    // The delegate may issue under `schema`, but the credential it would
+    // supersede belongs to another schema and it may not revoke at all.
+    const corrected = digest("diploma-supplement-2025-0012-corrected");
+    try {
+      await program.methods
+        .reissueCredential(corrected, { sha256: {} }, null, null, null, null)
+        .accountsPartial({
+          previous: credentialAddress(original),
+          credential: credentialAddress(corrected),
+          issuer,
+          schema,
+          delegate,
+          authority: staff.publicKey,
+        })
+        .signers([staff])
+        .rpc();
+      expect.fail("delegate superseded a credential outside its scope");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal(
+        "DelegateSchemaNotAllowed"
+      );
+    }
+
+    const account = await program.account.credential.fetch(credentialAddress(original));
+    expect(account.status).to.deep.equal({ active: {} });
+  });
+
+  it("rotates the issuer key without invalidating earlier credentials", async () => {
+    const before = digest("bachelor-of-science-2025-0020");
+    await issue(before);
+
+    const staff = anchor.web3.Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(staff.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+    );
+    const [delegate] = PublicKey.findProgramAddressSync(
+      [Buffer.from("delegate"), issuer.toBuffer(), staff.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .addIssuerDelegate(staff.publicKey, {
+        canIssue: true,
+        canRevoke: true,
+        maxIssuance: null,
+        expiresAt: null,
+        schema: null,
+      })
+      .accountsPartial({ issuer, authority })
+      .rpc();
+
+    const newKey = anchor.web3.Keypair.generate();
+    await program.methods
+      .rotateIssuerAuthority()
+      .accountsPartial({ issuer, signer: authority, newAuthority: newKey.publicKey })
+      .signers([newKey])
+      .rpc();
+
+    const account = await program.account.issuer.fetch(issuer);
+    expect(account.authority.toBase58()).to.equal(newKey.publicKey.toBase58());
+    expect(account.authorityHistory).to.have.length(1);
+    expect(account.authorityHistory[0].authority.toBase58()).to.equal(authority.toBase58());
+
+    try {
+      await issue(digest("bachelor-of-science-2025-0021"));
+      expect.fail("retired key issued a credential");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("UnregisteredIssuer");
+    }
+
+    // The retired key may have been compromised, so neither may its delegates.
+    const hash = digest("bachelor-of-science-2025-0022");
+    try {
+      await program.methods
+        .storeCredential(hash, { sha256: {} }, null, null, null, null)
+        .accountsPartial({
+          credential: credentialAddress(hash),
+          issuer,
+          schema,
+          delegate,
+          authority: staff.publicKey,
+        })
+        .signers([staff])
+        .rpc();
+      expect.fail("a delegate of the retired key issued a credential");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal(
+        "DelegatePredatesAuthority"
+      );
+    }
+
+    const result = await program.methods
+      .verifyCredential(before)
+      .accountsPartial({ issuer, credential: credentialAddress(before) })
+      .view();
+    expect(result.status).to.deep.equal({ valid: {} });
+    expect(result.issuingAuthority.toBase58()).to.equal(authority.toBase58());
+
+    // Hand the key back so the shared issuer stays usable.
+    await program.methods
+      .rotateIssuerAuthority()
+      .accountsPartial({ issuer, signer: newKey.publicKey, newAuthority: authority })
+      .signers([newKey])
+      .rpc();
+  });
+
+  it("drops the oldest authority record once the history is full", async () => {
+    const registrar = anchor.web3.Keypair.generate();
+    const [rotated] = PublicKey.findProgramAddressSync(
+      [Buffer.from("issuer"), registrar.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .registerIssuer(registrar.publicKey, "Example Seminary", "https://seminary.example", "")
+      .accounts({ admin })
+      .rpc();
+
+    let current = registrar;
+    for (let i = 0; i < 17; i++) {
+      const next = anchor.web3.Keypair.generate();
+      await program.methods
+        .rotateIssuerAuthority()
+        .accountsPartial({
+          issuer: rotated,
+          signer: current.publicKey,
+          newAuthority: next.publicKey,
+        })
+        .signers([current, next])
+        .rpc();
+      current = next;
+    }
+
+    const account = await program.account.issuer.fetch(rotated);
+    expect(account.authority.toBase58()).to.equal(current.publicKey.toBase58());
+    expect(account.authorityHistory).to.have.length(16);
+    expect(account.authorityHistory.map((record) => record.authority.toBase58())).not.to.include(
+      registrar.publicKey.toBase58()
+    );
+  });
+
+  it("issues through a 2-of-3 issuer multisig", async () => {
+    const registrar = anchor.web3.Keypair.generate();
+    const [alice, bob, carol] = [0, 1, 2].map(() => anchor.web3.Keypair.generate());
+    for (const key of [registrar, alice]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(key.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+      );
+    }
+
+    const [governed] = PublicKey.findProgramAddressSync(
+      [Buffer.from("issuer"), registrar.publicKey.toBuffer()],
+      program.programId
+    );
+    const [multisig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("multisig"), governed.toBuffer()],
+      program.programId
+    );
+    const [proposal] = PublicKey.findProgramAddressSync(
+      [Buffer.from("proposal"), multisig.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .registerIssuer(registrar.publicKey, "Example College", "https://college.example", "")
+      .accounts({ admin })
+      .rpc();
+    await program.methods
+      .createIssuerMultisig([alice.publicKey, bob.publicKey, carol.publicKey], 2)
+      .accountsPartial({ issuer: governed, authority: registrar.publicKey })
+      .signers([registrar])
+      .rpc();
+
+    const hash = digest("diploma-2025-0100");
+    const [credential] = PublicKey.findProgramAddressSync(
+      [Buffer.from("credential"), governed.toBuffer(), Buffer.from(hash)],
+      program.programId
+    );
+    const [tombstone] = PublicKey.findProgramAddressSync(
+      [Buffer.from("tombstone"), governed.toBuffer(), Buffer.from(hash)],
+      program.programId
+    );
+
+    await program.methods
+      .proposeAction({
+        issueCredential: {
+          schema,
+          hash,
+          algorithm: { sha256: {} },
+          subject: null,
+          validFrom: null,
+          validUntil: null,
+          metadata: null,
+        },
+      })
+      .accountsPartial({ issuer: governed, multisig, proposal, proposer: alice.publicKey })
+      .signers([alice])
+      .rpc();
+
+    const execute = () =>
+      program.methods
+        .executeAction()
+        .accountsPartial({
+          issuer: governed,
+          multisig,
+          proposal,
+          proposer: alice.publicKey,
+          target: credential,
+          tombstone,
+          schema,
+        })
+        .rpc();
+
+    try {
+      await execute();
+      expect.fail("executed with a single approval");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("InsufficientApprovals");
+    }
+
+    await program.methods
+      .approveAction()
+      .accountsPartial({ multisig, proposal, approver: bob.publicKey })
+      .signers([bob])
+      .rpc();
+    await execute();
+
+    const account = await program.account.credential.fetch(credential);
+    expect(account.issuer.toBase58()).to.equal(governed.toBase58());
+    expect(account.issuedBy.toBase58()).to.equal(multisig.toBase58());
+    expect(await provider.connection.getAccountInfo(proposal)).to.be.null;
+  });
+
+  it("voids pre-multisig delegates and Ethereum relaying once a multisig governs", async () => {
+    const [registrar, staff, relayer, alice] = [0, 1, 2, 3].map(() =>
+      anchor.web3.Keypair.generate()
+    );
+    for (const key of [registrar, staff, relayer]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(key.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+      );
+    }
+    const [governed] = PublicKey.findProgramAddressSync(
+      [Buffer.from("issuer"), registrar.publicKey.toBuffer()],
+      program.programId
+    );
+    const [delegate] = PublicKey.findProgramAddressSync(
+      [Buffer.from("delegate"), governed.toBuffer(), staff.publicKey.toBuffer()],
+      program.programId
+    );
+    const governedCredential = (hash: number[]) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("credential"), governed.toBuffer(), Buffer.from(hash)],
+        program.programId
+      )[0];
+
+    const relayedHash = digest("diploma-2025-0111");
+    const { ix: secp256k1Ix, ethAddress } = ethIssuance(governed, relayedHash);
+    await program.methods
+      .registerIssuer(registrar.publicKey, "Example Institute", "https://institute.example", "")
+      .accounts({ admin })
+      .rpc();
+    await program.methods
+      .addIssuerDelegate(staff.publicKey, {
+        canIssue: true,
+        canRevoke: true,
+        maxIssuance: null,
+        expiresAt: null,
+        schema: null,
+      })
+      .accountsPartial({ issuer: governed, authority: registrar.publicKey })
+      .signers([registrar])
+      .rpc();
+    await program.methods
+      .setIssuerEthAddress(ethAddress)
+      .accountsPartial({ issuer: governed, signer: registrar.publicKey })
+      .signers([registrar])
+      .rpc();
+    await program.methods
+      .createIssuerMultisig([alice.publicKey], 1)
+      .accountsPartial({ issuer: governed, authority: registrar.publicKey })
+      .signers([registrar])
+      .rpc();
+
+    const hash = digest("diploma-2025-0110");
+    try {
+      await program.methods
+        .storeCredential(hash, { sha256: {} }, null, null, null, null)
+        .accountsPartial({
+          credential: governedCredential(hash),
+          issuer: governed,
+          schema,
+          delegate,
+          authority: staff.publicKey,
+        })
+        .signers([staff])
+        .rpc();
+      expect.fail("a delegate of the retired key issued for a multisig issuer");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal(
+        "DelegatePredatesMultisig"
+      );
+    }
+
+    try {
+      await program.methods
+        .storeCredential(relayedHash, { sha256: {} }, null, null, null, null)
+        .accountsPartial({
+          credential: governedCredential(relayedHash),
+          issuer: governed,
+          schema,
+          authority: relayer.publicKey,
+          delegate: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .preInstructions([secp256k1Ix])
+        .signers([relayer])
+        .rpc();
+      expect.fail("an Ethereum signature issued for a multisig issuer");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("IssuerMultisigGoverned");
+    }
+  });
+
+  it("renews, suspends and rotates signers through a multisig", async () => {
+    const [registrar, alice, bob, carol] = [0, 1, 2, 3].map(() => anchor.web3.Keypair.generate());
+    for (const key of [registrar, alice, bob]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(key.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+      );
+    }
+    const [governed] = PublicKey.findProgramAddressSync(
+      [Buffer.from("issuer"), registrar.publicKey.toBuffer()],
+      program.programId
+    );
+    const [multisig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("multisig"), governed.toBuffer()],
+      program.programId
+    );
+    const hash = digest("diploma-2025-0120");
+    const [credential] = PublicKey.findProgramAddressSync(
+      [Buffer.from("credential"), governed.toBuffer(), Buffer.from(hash)],
+      program.programId
+    );
+    const [tombstone] = PublicKey.findProgramAddressSync(
+      [Buffer.from("tombstone"), governed.toBuffer(), Buffer.from(hash)],
+      program.programId
+    );
+
+    await program.methods
+      .registerIssuer(registrar.publicKey, "Example Academy", "https://academy.example", "")
+      .accounts({ admin })
+      .rpc();
+    await program.methods
+      .createIssuerMultisig([alice.publicKey, bob.publicKey], 1)
+      .accountsPartial({ issuer: governed, authority: registrar.publicKey })
+      .signers([registrar])
+      .rpc();
+
+    const propose = async (proposer: anchor.web3.Keypair, action: any) => {
+      const { proposalCount } = await program.account.issuerMultisig.fetch(multisig);
+      const [proposal] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), multisig.toBuffer(), proposalCount.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .proposeAction(action)
+        .accountsPartial({ issuer: governed, multisig, proposal, proposer: proposer.publicKey })
+        .signers([proposer])
+        .rpc();
+      return proposal;
+    };
+    const act = async (action: any, target: PublicKey = credential) =>
+      program.methods
+        .executeAction()
+        .accountsPartial({
+          issuer: governed,
+          multisig,
+          proposal: await propose(alice, action),
+          proposer: alice.publicKey,
+          target,
+          tombstone,
+          schema,
+        })
+        .rpc();
+
+    const now = Math.floor(Date.now() / 1000);
+    await act({
+      issueCredential: {
+        schema,
+        hash,
+        algorithm: { sha256: {} },
+        subject: null,
+        validFrom: null,
+        validUntil: new anchor.BN(now + 3600),
+        metadata: null,
+      },
+    });
+    await act({ renewCredential: { hash, validUntil: new anchor.BN(now + 7200) } });
+    await act({ setCredentialSuspended: { hash, suspended: true } });
+
+    const account = await program.account.credential.fetch(credential);
+    expect(account.validUntil.toNumber()).to.equal(now + 7200);
+    expect(account.renewalCount).to.equal(1);
+    expect(account.status).to.deep.equal({ suspended: {} });
+
+    await act(
+      { changeSigners: { signers: [bob.publicKey, carol.publicKey], threshold: 2 } },
+      multisig
+    );
+    expect((await program.account.issuerMultisig.fetch(multisig)).threshold).to.equal(2);
+    try {
+      await propose(alice, { setCredentialSuspended: { hash, suspended: false } });
+      expect.fail("a removed signer proposed an action");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("NotMultisigSigner");
+    }
+
+    const pending = await propose(bob, { setCredentialSuspended: { hash, suspended: false } });
+    await program.methods
+      .cancelAction()
+      .accountsPartial({ multisig, proposal: pending, proposer: bob.publicKey })
+      .signers([bob])
+      .rpc();
+    expect(await provider.connection.getAccountInfo(pending)).to.be.null;
+  });
+
+  it("anchors batches, registers schemas and closes credentials through a multisig", async () => {
+    const [registrar, alice] = [0, 1].map(() => anchor.web3.Keypair.generate());
+    for (const key of [registrar, alice]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(key.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+      );
+    }
+    const [governed] = PublicKey.findProgramAddressSync(
+      [Buffer.from("issuer"), registrar.publicKey.toBuffer()],
+      program.programId
+    );
+    const [multisig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("multisig"), governed.toBuffer()],
+      program.programId
+    );
+    const pda = (...seeds: Buffer[]) =>
+      PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+
+    await program.methods
+      .registerIssuer(registrar.publicKey, "Example Institute", "https://institute.example", "")
+      .accounts({ admin })
+      .rpc();
+    await program.methods
+      .createIssuerMultisig([alice.publicKey], 1)
+      .accountsPartial({ issuer: governed, authority: registrar.publicKey })
+      .signers([registrar])
+      .rpc();
+
+    const act = async (
+      action: any,
+      accounts: { target: PublicKey; related?: PublicKey; tombstone?: PublicKey }
+    ) => {
+      const { proposalCount } = await program.account.issuerMultisig.fetch(multisig);
+      const proposal = pda(
+        Buffer.from("proposal"),
+        multisig.toBuffer(),
+        proposalCount.toArrayLike(Buffer, "le", 8)
+      );
+      await program.methods
+        .proposeAction(action)
+        .accountsPartial({ issuer: governed, multisig, proposal, proposer: alice.publicKey })
+        .signers([alice])
+        .rpc();
+      await program.methods
+        .executeAction()
+        .accountsPartial({
+          issuer: governed,
+          multisig,
+          proposal,
+          proposer: alice.publicKey,
+          related: null,
+          tombstone: null,
+          schema,
+          ...accounts,
+        })
+        .rpc();
+    };
+
+    const version = Buffer.alloc(2);
+    version.writeUInt16LE(1);
+    const ownSchema = pda(
+      Buffer.from("schema"),
+      governed.toBuffer(),
+      Buffer.from("certificate"),
+      version
+    );
+    await act(
+      {
+        registerSchema: {
+          schemaId: "certificate",
+          version: 1,
+          name: "Professional certificate",
+          contentHash: digest('{"$id":"certificate","version":1}'),
+          uri: "https://schemas.example.edu/certificate/v1.json",
+        },
+      },
+      { target: ownSchema }
+    );
+    const registered = await program.account.credentialSchema.fetch(ownSchema);
+    expect(registered.registry.toBase58()).to.equal(governed.toBase58());
+    expect(registered.registeredBy.toBase58()).to.equal(multisig.toBase58());
+
+    const root = digest("institute-cohort-2025");
+    const batch = pda(Buffer.from("batch"), governed.toBuffer(), Buffer.from(root));
+    const revocationList = pda(Buffer.from("revocation_list"), batch.toBuffer());
+    await act(
+      { storeCredentialBatch: { root, leafCount: 4, algorithm: { sha256: {} } } },
+      { target: batch, related: revocationList }
+    );
+    const anchored = await program.account.credentialBatch.fetch(batch);
+    expect(anchored.issuedBy.toBase58()).to.equal(multisig.toBase58());
+    expect(anchored.leafCount).to.equal(4);
+
+    const hash = digest("certificate-2025-0130");
+    const credential = pda(Buffer.from("credential"), governed.toBuffer(), Buffer.from(hash));
+    const tombstone = pda(Buffer.from("tombstone"), governed.toBuffer(), Buffer.from(hash));
+    await act(
+      {
+        issueCredential: {
+          schema,
+          hash,
+          algorithm: { sha256: {} },
+          subject: null,
+          validFrom: null,
+          validUntil: null,
+          metadata: null,
+        },
+      },
+      { target: credential, tombstone }
+    );
+    await act({ closeCredential: { hash } }, { target: credential, tombstone });
+    expect(await provider.connection.getAccountInfo(credential)).to.be.null;
+    const closed = await program.account.tombstone.fetch(tombstone);
+    expect(closed.closedBy.toBase58()).to.equal(multisig.toBase58());
+
+    const signingKey = anchor.web3.Keypair.generate().publicKey;
+    await act({ setSigningKey: { signingKey } }, { target: governed });
+    const account = await program.account.issuer.fetch(governed);
+    expect(account.signingKey.toBase58()).to.equal(signingKey.toBase58());
+  });
+
+  it("links a credential to its off-chain document", async () => {
+    const hash = digest("master-of-science-2025-0030");
+    const metadata = {
+      metadataUri: "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+      contentDigest: digest("master-of-science-2025-0030.pdf"),
+    };
+    await program.methods
+      .storeCredential(hash, { sha256: {} }, null, null, null, metadata)
+      .accountsPartial({
+        credential: credentialAddress(hash),
+        issuer,
+        schema,
+        authority,
+        delegate: null,
+      })
+      .rpc();
+
+    const result = await program.methods
+      .verifyCredential(hash)
+      .accountsPartial({ issuer, credential: credentialAddress(hash) })
+      .view();
+    expect(result.metadata.metadataUri).to.equal(metadata.metadataUri);
+    expect(result.metadata.contentDigest).to.deep.equal(metadata.contentDigest);
+  });
+
+  it("requires the issuer signing key's signature once one is set", async () => {
+    const signingKey = anchor.web3.Keypair.generate();
+    await program.methods
+      .setIssuerSigningKey(signingKey.publicKey)
+      .accountsPartial({ issuer, authority })
+      .rpc();
+
+    const hash = digest("doctor-of-philosophy-2025-0040");
+    const store = () =>
+      program.methods
+        .storeCredential(hash, { sha256: {} }, null, null, null, null)
+        .accountsPartial({
+          credential: credentialAddress(hash),
+          issuer,
+          schema,
+          authority,
+          delegate: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        });
+
+    try {
+      await store().rpc();
+      expect.fail("issued without the issuer signature");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("MissingIssuerSignature");
+    }
+
+    const message = Buffer.concat([
+      Buffer.from("blockverify:credential:v1"),
+      program.programId.toBuffer(),
+      issuer.toBuffer(),
+      schema.toBuffer(),
+      Buffer.from(hash),
+      PublicKey.default.toBuffer(),
+    ]);
+    await store()
+      .preInstructions([
+        Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: signingKey.secretKey,
+          message,
+        }),
+      ])
+      .rpc();
+
+    const account = await program.account.credential.fetch(credentialAddress(hash));
+    expect(account.attestation.ed25519.signingKey.toBase58()).to.equal(
+      signingKey.publicKey.toBase58()
+    );
+
+    // A batch root carries no signature for the credentials under it.
+    const root = digest("unsigned-cohort-2025");
+    const [batch] = PublicKey.findProgramAddressSync(
+      [Buffer.from("batch"), issuer.toBuffer(), Buffer.from(root)],
+      program.programId
+    );
+    try {
+      await program.methods
+        .storeCredentialBatch(root, 3, { sha256: {} })
+        .accountsPartial({ batch, issuer, authority })
+        .rpc();
+      expect.fail("anchored a batch the signing key never signed");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal(
+        "SigningKeyPreventsBatch"
+      );
+    }
+
+    await program.methods.setIssuerSigningKey(null).accountsPartial({ issuer, authority }).rpc();
+  });
+
+  it("rejects Ed25519 attestations by another key, for other terms or from elsewhere", async () => {
+    const signingKey = anchor.web3.Keypair.generate();
+    await program.methods
+      .setIssuerSigningKey(signingKey.publicKey)
+      .accountsPartial({ issuer, authority })
+      .rpc();
+
+    const hash = digest("doctor-of-philosophy-2025-0041");
+    const subject = anchor.web3.Keypair.generate().publicKey;
+    const message = (schemaKey: PublicKey, subjectKey: PublicKey) =>
+      Buffer.concat([
+        Buffer.from("blockverify:credential:v1"),
+        program.programId.toBuffer(),
+        issuer.toBuffer(),
+        schemaKey.toBuffer(),
+        Buffer.from(hash),
+        subjectKey.toBuffer(),
+      ]);
+    const signed = (privateKey: Uint8Array, schemaKey: PublicKey, subjectKey: PublicKey) =>
+      Ed25519Program.createInstructionWithPrivateKey({
+        privateKey,
+        message: message(schemaKey, subjectKey),
+      });
+    const store = (...preInstructions: anchor.web3.TransactionInstruction[]) =>
+      program.methods
+        .storeCredential(hash, { sha256: {} }, subject, null, null, null)
+        .accountsPartial({
+          credential: credentialAddress(hash),
+          issuer,
+          schema,
+          authority,
+          delegate: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .preInstructions(preInstructions)
+        .rpc();
+
+    // A copy of a valid instruction whose offsets all point at the original,
+    // which the precompile accepts but the program must not.
+    const valid = signed(signingKey.secretKey, schema, subject);
+    const elsewhere = new anchor.web3.TransactionInstruction({
+      programId: valid.programId,
+      keys: [],
+      data: Buffer.from(valid.data),
+    });
+    for (const at of [4, 8, 14]) {
+      elsewhere.data.writeUInt16LE(0, at);
+    }
+
+    const forged = [
+      [signed(anchor.web3.Keypair.generate().secretKey, schema, subject)],
+      [signed(signingKey.secretKey, schema, PublicKey.default)],
+      [signed(signingKey.secretKey, anchor.web3.Keypair.generate().publicKey, subject)],
+      [valid, elsewhere],
+    ];
+    for (const preInstructions of forged) {
+      try {
+        await store(...preInstructions);
+        expect.fail("accepted a forged Ed25519 attestation");
+      } catch (err) {
+        expect((err as anchor.AnchorError).error.errorCode.code).to.equal(
+          "InvalidIssuerSignature"
+        );
+      }
+    }
+
+    await store(valid);
+    await program.methods.setIssuerSigningKey(null).accountsPartial({ issuer, authority }).rpc();
+  });
+
+  it("rejects Ethereum attestations by another key, for other terms or elsewhere", async () => {
+    const hash = digest("bachelor-of-laws-2025-0052");
+    const ethKey = randomBytes(32);
+    const { ix: valid, ethAddress } = ethIssuance(issuer, hash, ethKey);
+    await program.methods
+      .setIssuerEthAddress(ethAddress)
+      .accountsPartial({ issuer, signer: authority })
+      .rpc();
+
+    const relayer = anchor.web3.Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(relayer.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+    );
+    const store = (
+      subject: PublicKey | null,
+      ...preInstructions: anchor.web3.TransactionInstruction[]
+    ) =>
+      program.methods
+        .storeCredential(hash, { sha256: {} }, subject, null, null, null)
+        .accountsPartial({
+          credential: credentialAddress(hash),
+          issuer,
+          schema,
+          authority: relayer.publicKey,
+          delegate: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .preInstructions(preInstructions)
+        .signers([relayer])
+        .rpc();
+
+    // web3.js points every offset at instruction 0, so a second copy of a
+    // valid instruction verifies the first one's signature, not its own.
+    const forged: [PublicKey | null, anchor.web3.TransactionInstruction[]][] = [
+      [null, [ethIssuance(issuer, hash).ix]],
+      [anchor.web3.Keypair.generate().publicKey, [valid]],
+      [null, [valid, valid]],
+    ];
+    for (const [subject, preInstructions] of forged) {
+      try {
+        await store(subject, ...preInstructions);
+        expect.fail("accepted a forged secp256k1 attestation");
+      } catch (err) {
+        expect((err as anchor.AnchorError).error.errorCode.code).to.equal(
+          "InvalidIssuerSignature"
+        );
+      }
+    }
+
+    await store(null, valid);
+    await program.methods
+      .setIssuerEthAddress(null)
+      .accountsPartial({ issuer, signer: authority })
+      .rpc();
+  });
+
+  it("lets any relayer issue with the issuer's Ethereum signature", async () => {
+    const hash = digest("bachelor-of-laws-2025-0050");
+    const ethKey = randomBytes(32);
+    const { ix: secp256k1Ix, ethAddress } = ethIssuance(issuer, hash, ethKey);
+
+    await program.methods
+      .setIssuerEthAddress(ethAddress)
+      .accountsPartial({ issuer, signer: authority })
+      .rpc();
+
+    const relayer = anchor.web3.Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(relayer.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+    );
+    await program.methods
+      .storeCredential(hash, { sha256: {} }, null, null, null, null)
+      .accountsPartial({
+        credential: credentialAddress(hash),
+        issuer,
+        schema,
+        authority: relayer.publicKey,
+        delegate: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .preInstructions([secp256k1Ix])
+      .signers([relayer])
+      .rpc();
+
+    const account = await program.account.credential.fetch(credentialAddress(hash));
+    expect(account.attestation.secp256k1.ethAddress).to.deep.equal(ethAddress);
+
+    // Reissuing needs a signature over the superseded credential too.
+    const corrected = digest("bachelor-of-laws-2025-0050-corrected");
+    const reissue = (ix: anchor.web3.TransactionInstruction) =>
+      program.methods
+        .reissueCredential(corrected, { sha256: {} }, null, null, null, null)
+        .accountsPartial({
+          previous: credentialAddress(hash),
+          credential: credentialAddress(corrected),
+          issuer,
+          schema,
+          authority: relayer.publicKey,
+          delegate: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .preInstructions([ix])
+        .signers([relayer])
+        .rpc();
+    try {
+      await reissue(ethIssuance(issuer, corrected, ethKey).ix);
+      expect.fail("an issuance signature superseded an existing credential");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("InvalidIssuerSignature");
+    }
+    await reissue(ethIssuance(issuer, corrected, ethKey, credentialAddress(hash)).ix);
+    expect(
+      (await program.account.credential.fetch(credentialAddress(hash))).supersededBy.toBase58()
+    ).to.equal(credentialAddress(corrected).toBase58());
+
+    // The Ethereum key stands in for the authority, not for the signing key.
+    const signed = digest("bachelor-of-laws-2025-0051");
+    await program.methods
+      .setIssuerSigningKey(anchor.web3.Keypair.generate().publicKey)
+      .accountsPartial({ issuer, authority })
+      .rpc();
+    try {
+      await program.methods
+        .storeCredential(signed, { sha256: {} }, null, null, null, null)
+        .accountsPartial({
+          credential: credentialAddress(signed),
+          issuer,
+          schema,
+          authority: relayer.publicKey,
+          delegate: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .preInstructions([ethIssuance(issuer, signed, ethKey).ix])
+        .signers([relayer])
+        .rpc();
+      expect.fail("an Ethereum signature bypassed the issuer signing key");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("MissingIssuerSignature");
+    }
+    await program.methods.setIssuerSigningKey(null).accountsPartial({ issuer, authority }).rpc();
+
+    await program.methods
+      .setIssuerEthAddress(null)
+      .accountsPartial({ issuer, signer: authority })
+      .rpc();
+  });
+
+  it("proves a single claim of a selective-disclosure credential", async () => {
+    const sha256 = (...parts: Buffer[]) =>
+      createHash("sha256").update(Buffer.concat(parts)).digest();
+    const claims = [
+      { name: "degree", value: Buffer.from("BSc Computer Science"), salt: randomBytes(32) },
+      { name: "gpa", value: Buffer.from("3.8"), salt: randomBytes(32) },
+    ];
+    const leaves = claims.map(({ name, value, salt }) => {
+      const nameLen = Buffer.alloc(4);
+      nameLen.writeUInt32LE(name.length);
+      return sha256(Buffer.from([0]), sha256(salt, nameLen, Buffer.from(name), value));
+    });
+    const claimCount = Buffer.alloc(4);
+    claimCount.writeUInt32LE(claims.length);
+    const treeRoot = sha256(Buffer.from([1]), leaves[0], leaves[1]);
+    const root = Array.from(sha256(Buffer.from([2]), claimCount, treeRoot));
+
+    await program.methods
+      .storeCredential(root, { claimsRoot: {} }, null, null, null, null)
+      .accountsPartial({
+        credential: credentialAddress(root),
+        issuer,
+        schema,
+        authority,
+        delegate: null,
+      })
+      .rpc();
+
+    const open = (salt: Buffer) => ({
+      index: 1,
+      salt: Array.from(salt),
+      name: "gpa",
+      value: Buffer.from("3.8"),
+      proof: [Array.from(leaves[0])],
+    });
+    const result = await program.methods
+      .verifyClaim(root, 2, [open(claims[1].salt)])
+      .accountsPartial({ issuer, credential: credentialAddress(root) })
+      .view();
+    expect(result.status).to.deep.equal({ valid: {} });
+
+    try {
+      await program.methods
+        .verifyClaim(root, 2, [open(randomBytes(32))])
+        .accountsPartial({ issuer, credential: credentialAddress(root) })
+        .view();
+      expect.fail("opened a claim with the wrong salt");
+    } catch (err) {
+      expect(String(err)).to.contain("InvalidClaimProof");
+    }
+
+    try {
+      await program.methods
+        .verifyClaim(root, 3, [open(claims[1].salt)])
+        .accountsPartial({ issuer, credential: credentialAddress(root) })
+        .view();
+      expect.fail("opened a claim against another claim count");
+    } catch (err) {
+      expect(String(err)).to.contain("InvalidClaimProof");
+    }
+  });
+
+  it("opens a Poseidon commitment with the on-chain syscall", async () => {
+    const field = (n: number) => {
+      const element = Buffer.alloc(32);
+      element.writeUInt32BE(n, 28);
+      return Array.from(element);
+    };
+    // Poseidon(1, 2) over BN254, as computed by circomlib and the Rust client.
+    const hash = Array.from(
+      Buffer.from("115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a", "hex")
+    );
+
+    await program.methods
+      .storeCredential(hash, { poseidon: {} }, null, null, null, null)
+      .accountsPartial({
+        credential: credentialAddress(hash),
+        issuer,
+        schema,
+        authority,
+        delegate: null,
+      })
+      .rpc();
+
+    const result = await program.methods
+      .verifyCommitmentOpening(hash, field(1), [field(2)])
+      .accountsPartial({ issuer, credential: credentialAddress(hash) })
+      .view();
+    expect(result.status).to.deep.equal({ valid: {} });
+
+    try {
+      await program.methods
+        .verifyCommitmentOpening(hash, field(1), [field(3)])
+        .accountsPartial({ issuer, credential: credentialAddress(hash) })
+        .view();
+      expect.fail("opened a commitment with the wrong value");
+    } catch (err) {
+      expect(String(err)).to.contain("InvalidCommitmentOpening");
+    }
+  });
+
+  it("verifies a zero-knowledge predicate over an anonymity set of credentials", async () => {
+    // Generated by `cargo run -p credential-client --example zk_predicate_fixture`.
+    const fixture = JSON.parse(readFileSync("tests/fixtures/zk-predicate.json", "utf8"));
+    const bytes = (hex: string) => Array.from(Buffer.from(hex, "hex"));
+    const [verifier] = PublicKey.findProgramAddressSync(
+      [Buffer.from("predicate"), Buffer.from(fixture.predicateId)],
+      program.programId
+    );
+
+    const commitments: number[][] = fixture.commitments.map(bytes);
+    for (const hash of commitments) {
+      await program.methods
+        .storeCredential(hash, { poseidon: {} }, null, null, null, null)
+        .accountsPartial({
+          credential: credentialAddress(hash),
+          issuer,
+          schema,
+          authority,
+          delegate: null,
+        })
+        .rpc();
+    }
+
+    const key = {
+      alphaG1: bytes(fixture.key.alphaG1),
+      betaG2: bytes(fixture.key.betaG2),
+      gammaG2: bytes(fixture.key.gammaG2),
+      deltaG2: bytes(fixture.key.deltaG2),
+    };
+    await program.methods
+      .registerPredicateVerifier(
+        fixture.predicateId,
+        fixture.credentialCount,
+        fixture.parameters.map(bytes),
+        key
+      )
+      .accountsPartial({ schema, verifier, admin })
+      .rpc();
+    await program.methods
+      .extendPredicateVerifier(fixture.ic.map(bytes))
+      .accountsPartial({ verifier, admin })
+      .rpc();
+
+    const anonymitySet = commitments.flatMap((hash) => [
+      { pubkey: issuer, isSigner: false, isWritable: false },
+      { pubkey: credentialAddress(hash), isSigner: false, isWritable: false },
+    ]);
+    const proof = {
+      a: bytes(fixture.proof.a),
+      b: bytes(fixture.proof.b),
+      c: bytes(fixture.proof.c),
+    };
+    const nullifier = (challenge: number[]) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("predicate_nullifier"), verifier.toBuffer(), Buffer.from(challenge)],
+        program.programId
+      )[0];
+    const verify = (challenge: number[]) =>
+      program.methods
+        .verifyZkPredicate(proof, challenge)
+        .accountsPartial({ verifier, nullifier: nullifier(challenge), payer: authority })
+        .remainingAccounts(anonymitySet)
+        .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 })])
+        .rpc();
+
+    const challenge = bytes(fixture.challenge);
+    await verify(challenge);
+    const consumed = await program.account.predicateNullifier.fetch(nullifier(challenge));
+    expect(consumed.verifier.toBase58()).to.equal(verifier.toBase58());
+
+    // The proof is bound to its challenge, which the first verification consumed.
+    try {
+      await verify(challenge);
+      expect.fail("replayed a consumed challenge");
+    } catch (err) {
+      expect(String(err)).to.contain("already in use");
+    }
+    try {
+      await verify(Array.from(Buffer.alloc(32, 1)));
+      expect.fail("verified a proof against another challenge");
+    } catch (err) {
+      expect(String(err)).to.contain("InvalidZkProof");
+    }
   });
 });
@@ -0,0 +1,159 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { createHash } from "crypto";
+import { expect } from "chai";
+import { CredentialContract } from "../target/types/credential_contract";
+import { CredentialGate } from "../target/types/credential_gate";
+
+// Runs after credential-contract.ts, which initializes the config and
+// registers the provider wallet as an issuer.
+describe("credential-gate", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const credentials = anchor.workspace.credentialContract as Program<CredentialContract>;
+  const program = anchor.workspace.credentialGate as Program<CredentialGate>;
+  const authority = provider.wallet.publicKey;
+
+  const [issuer] = PublicKey.findProgramAddressSync(
+    [Buffer.from("issuer"), authority.toBuffer()],
+    credentials.programId
+  );
+  const [config] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    credentials.programId
+  );
+  const schemaVersion = Buffer.alloc(2);
+  schemaVersion.writeUInt16LE(1);
+  const [schema] = PublicKey.findProgramAddressSync(
+    [Buffer.from("schema"), config.toBuffer(), Buffer.from("bachelor-degree"), schemaVersion],
+    credentials.programId
+  );
+  const [gate] = PublicKey.findProgramAddressSync(
+    [Buffer.from("gate"), authority.toBuffer()],
+    program.programId
+  );
+
+  const digest = (document: string) =>
+    Array.from(createHash("sha256").update(document).digest());
+
+  const credentialAddress = (hash: number[]) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("credential"), issuer.toBuffer(), Buffer.from(hash)],
+      credentials.programId
+    )[0];
+
+  const tombstoneAddress = (hash: number[]) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("tombstone"), issuer.toBuffer(), Buffer.from(hash)],
+      credentials.programId
+    )[0];
+
+  const join = (hash: number[]) =>
+    program.methods
+      .join(hash)
+      .accountsPartial({
+        gate,
+        issuer,
+        credential: credentialAddress(hash),
+        tombstone: tombstoneAddress(hash),
+        member: authority,
+      })
+      .rpc();
+
+  it("creates a gate bound to one issuer and schema", async () => {
+    await program.methods.createGate().accounts({ issuer, schema, authority }).rpc();
+
+    const account = await program.account.gate.fetch(gate);
+    expect(account.issuer.toBase58()).to.equal(issuer.toBase58());
+    expect(account.schema.toBase58()).to.equal(schema.toBase58());
+  });
+
+  it("rejects a credential of another schema", async () => {
+    const [transcript] = PublicKey.findProgramAddressSync(
+      [Buffer.from("schema"), issuer.toBuffer(), Buffer.from("transcript"), schemaVersion],
+      credentials.programId
+    );
+    await credentials.methods
+      .registerSchema(
+        "transcript",
+        1,
+        "Academic transcript",
+        digest('{"$id":"transcript","version":1}'),
+        "https://schemas.example.edu/transcript/v1.json"
+      )
+      .accountsPartial({ schema: transcript, issuer, authority })
+      .rpc();
+
+    const hash = digest("gate-credential-other-schema");
+    await credentials.methods
+      .storeCredential(hash, { sha256: {} }, authority, null, null, null)
+      .accountsPartial({
+        credential: credentialAddress(hash),
+        issuer,
+        schema: transcript,
+        authority,
+        delegate: null,
+      })
+      .rpc();
+
+    try {
+      await join(hash);
+      expect.fail("joined with a credential of another schema");
+    } catch (err) {
+      expect(String(err)).to.contain("SchemaMismatch");
+    }
+  });
+
+  it("rejects a credential issued to someone else", async () => {
+    const hash = digest("gate-credential-other-subject");
+    await credentials.methods
+      .storeCredential(
+        hash,
+        { sha256: {} },
+        anchor.web3.Keypair.generate().publicKey,
+        null,
+        null,
+        null
+      )
+      .accountsPartial({
+        credential: credentialAddress(hash),
+        issuer,
+        schema,
+        authority,
+        delegate: null,
+      })
+      .rpc();
+
+    try {
+      await join(hash);
+      expect.fail("joined with another subject's credential");
+    } catch (err) {
+      expect(String(err)).to.contain("SubjectMismatch");
+    }
+  });
+
+  it("admits the holder of a valid credential", async () => {
+    const hash = digest("gate-credential-holder");
+    await credentials.methods
+      .storeCredential(hash, { sha256: {} }, authority, null, null, null)
+      .accountsPartial({
+        credential: credentialAddress(hash),
+        issuer,
+        schema,
+        authority,
+        delegate: null,
+      })
+      .rpc();
+
+    await join(hash);
+
+    const [membership] = PublicKey.findProgramAddressSync(
+      [Buffer.from("membership"), gate.toBuffer(), authority.toBuffer()],
+      program.programId
+    );
+    const account = await program.account.membership.fetch(membership);
+    expect(account.credential.toBase58()).to.equal(credentialAddress(hash).toBase58());
+  });
+});